@@ -1,26 +1,54 @@
 mod info_bar;
+mod list_panel;
 mod log_area;
+mod pane;
+mod parent;
+mod peek_view;
+mod preview;
 mod pwd;
 mod sidemenu;
 mod state_bar;
+mod tab_bar;
 mod viewer;
 
 use self::{
-    info_bar::InfoBar, log_area::LogArea, pwd::Working, sidemenu::Sidemenu, state_bar::StateBar,
-    viewer::Viewer,
+    info_bar::InfoBar, list_panel::ListPanel, log_area::LogArea, pane::Pane, parent::Parent,
+    peek_view::PeekView, preview::Preview, pwd::Working, sidemenu::Sidemenu, state_bar::StateBar,
+    tab_bar::TabBar, viewer::Viewer,
 };
-use crate::state::State;
+use crate::{component::EntryKind, highlight::Line, state::State};
+use crossterm::style::Color;
 use std::sync::Arc;
 
 pub fn draw(state: Arc<State>) {
     use std::io::{self, Write};
 
-    let layout = gen_layout(state.term_size.load(), state.flag.is_sidemenu_opened.get());
+    let layout = gen_layout(
+        state.term_size.load(),
+        state.flag.is_sidemenu_opened.get(),
+        state.flag.is_preview_opened.get(),
+        state.tabs.len() > 1,
+        if state.flag.is_dual_pane.get() {
+            Split::Dual {
+                is_right_focused: state.flag.is_right_focused.get(),
+            }
+        } else if state.flag.is_miller.get() {
+            Split::Miller
+        } else {
+            Split::Single
+        },
+    );
     let layout_key = layout.hashcode();
 
     let hashes = &state.canvas_hashes;
+    let entries = state.entries();
+    let version = state.snapshot.version();
 
-    let sidemenu = Sidemenu::new(state.sidemenu.cursor.current());
+    let sidemenu = Sidemenu::new(
+        state.sidemenu.cursor.current(),
+        state.sidemenu.elements(),
+        state.marks.list(),
+    );
     let sidemenu_hash = sidemenu.make_hash(layout_key);
 
     if hashes.update(Sidemenu::ID, sidemenu_hash) != Some(sidemenu_hash) {
@@ -28,6 +56,23 @@ pub fn draw(state: Arc<State>) {
         log::info!("Render the {}", Sidemenu::ID);
     }
 
+    if state.tabs.len() > 1 {
+        let mut dirs = state.tabs.dirs();
+        let current = state.tabs.current();
+
+        if let Some(dir) = dirs.get_mut(current) {
+            *dir = state.work_dir.get();
+        }
+
+        let tabbar = TabBar::new(dirs, current);
+        let tabbar_hash = tabbar.make_hash(layout_key);
+
+        if hashes.update(TabBar::ID, tabbar_hash) != Some(tabbar_hash) {
+            tabbar.draw(layout.get(TabBar::ID));
+            log::info!("Render the {}", TabBar::ID);
+        }
+    }
+
     let working = Working::new(state.work_dir.get());
     let working_hash = working.make_hash(layout_key);
 
@@ -36,9 +81,20 @@ pub fn draw(state: Arc<State>) {
         log::info!("Render the {}", Working::ID);
     }
 
+    let grep = state.grep.load();
+    let cursor_pos = state.file_view.cursor.current();
+    let search_matches = (!grep.is_empty()).then(|| {
+        let matches = state.search.matches(&grep, &entries, version);
+
+        (matches.partition_point(|&i| i <= cursor_pos), matches.len())
+    });
+
     let infobar = InfoBar::new(
-        state.work_dir.get(),
-        state.file_view.cursor.current(),
+        entries.len(),
+        state.snapshot.hidden_len(),
+        state.filter.load(),
+        search_matches,
+        cursor_pos,
         layout.get(Viewer::ID).height.into(),
     );
     let infobar_hash = infobar.make_hash(layout_key);
@@ -48,26 +104,123 @@ pub fn draw(state: Arc<State>) {
         log::info!("Render the {}", InfoBar::ID);
     }
 
-    let viewer = Viewer::new(
-        state.work_dir.get(),
-        state.file_view.cursor.current(),
-        state.file_view.selection.collect(),
-        state.grep.load(),
-        state.input.tag(),
-        state
-            .input
-            .is_enable()
-            .then_some(state.input.input.buf_clone()),
-        state.input.input.cursor(),
-    );
-    let viewer_hash = viewer.make_hash(layout_key);
+    if let Some(kind) = state.list.kind() {
+        let list_panel = ListPanel::new(
+            kind,
+            state.list.items(),
+            state.list.version(),
+            state.list.cursor.current(),
+            state.list.selection.collect(),
+        );
+        let list_panel_hash = list_panel.make_hash(layout_key);
+
+        if hashes.update(Viewer::ID, list_panel_hash) != Some(list_panel_hash) {
+            list_panel.draw(layout.get(Viewer::ID));
+            log::info!("Render the {} as list", Viewer::ID);
+        }
+    } else if let Some(target) = state.peek.target() {
+        let peek_view = PeekView::new(
+            target,
+            state.peek.info(),
+            state.peek.lines(),
+            state.peek.scroll.current(),
+        );
+        let peek_view_hash = peek_view.make_hash(layout_key);
+
+        if hashes.update(Viewer::ID, peek_view_hash) != Some(peek_view_hash) {
+            peek_view.draw(layout.get(Viewer::ID));
+            log::info!("Render the {} as peek", Viewer::ID);
+        }
+    } else {
+        let viewer = Viewer::new(
+            entries.clone(),
+            version,
+            state.file_view.cursor.current(),
+            state.file_view.selection.collect(),
+            state.grep.load(),
+            state.input.tag(),
+            state
+                .input
+                .is_enable()
+                .then_some(state.input.input.buf_clone()),
+            state.input.input.cursor(),
+        );
+        let viewer_hash = viewer.make_hash(layout_key);
+
+        if hashes.update(Viewer::ID, viewer_hash) != Some(viewer_hash) {
+            viewer.draw(layout.get(Viewer::ID));
+            log::info!("Render the {}", Viewer::ID);
+        }
+    }
 
-    if hashes.update(Viewer::ID, viewer_hash) != Some(viewer_hash) {
-        viewer.draw(layout.get(Viewer::ID));
-        log::info!("Render the {}", Viewer::ID);
+    if state.flag.is_dual_pane.get() {
+        let show_hidden = state.flag.is_hidden_shown.get();
+        let pane_entries = state.pane.entries(show_hidden);
+        let pane_cursor = state.pane.cursor(&pane_entries);
+        let pane_rect = layout.get(Pane::ID);
+
+        let pane = Pane::new(
+            Working::new(state.pane.dir()),
+            InfoBar::new(
+                pane_entries.len(),
+                state.pane.hidden_len(),
+                state.pane.filter(),
+                None,
+                pane_cursor,
+                pane_rect.height.saturating_sub(2).into(),
+            ),
+            Viewer::new(
+                pane_entries,
+                state.pane.version(),
+                pane_cursor,
+                vec![],
+                state.pane.grep(),
+                None,
+                None,
+                0,
+            ),
+        );
+        let pane_hash = pane.make_hash(layout_key);
+
+        if hashes.update(Pane::ID, pane_hash) != Some(pane_hash) {
+            pane.draw(pane_rect);
+            log::info!("Render the {}", Pane::ID);
+        }
+    } else if state.flag.is_preview_opened.get() || state.flag.is_miller.get() {
+        let preview = Preview::new(
+            entries
+                .get(state.file_view.cursor.current())
+                .map(|entry| entry.path.clone()),
+            version,
+            state.flag.is_hidden_shown.get(),
+        );
+        let preview_hash = preview.make_hash(layout_key);
+
+        if hashes.update(Preview::ID, preview_hash) != Some(preview_hash) {
+            preview.draw(layout.get(Preview::ID));
+            log::info!("Render the {}", Preview::ID);
+        }
     }
 
-    let statebar = StateBar::new(state.mode.get(), state.proc_counter.now());
+    if state.flag.is_miller.get() && !state.flag.is_dual_pane.get() {
+        let parent = Parent::new(
+            state.work_dir.get(),
+            state.flag.is_hidden_shown.get(),
+            version,
+        );
+        let parent_hash = parent.make_hash(layout_key);
+
+        if hashes.update(Parent::ID, parent_hash) != Some(parent_hash) {
+            parent.draw(layout.get(Parent::ID));
+            log::info!("Render the {}", Parent::ID);
+        }
+    }
+
+    let statebar = StateBar::new(
+        state.mode.get(),
+        state.jobs.running(),
+        state.jobs.transfer(),
+    );
     let statebar_hash = statebar.make_hash(layout_key);
 
     if hashes.update(StateBar::ID, statebar_hash) != Some(statebar_hash) {
@@ -136,6 +289,42 @@ fn printin<S: AsRef<str>>(rect: Rect, (rel_x, rel_y): (u16, u16), s: S) {
     crossterm::queue!(io::stdout(), MoveTo(abs_x, abs_y), Print(text), ResetColor,).ok();
 }
 
+fn kind_color(kind: EntryKind) -> Color {
+    use crate::config;
+
+    let theme = &config::get().theme;
+
+    match kind {
+        EntryKind::SymlinkFile | EntryKind::SymlinkDir => theme.item_symlink,
+        EntryKind::Dir => theme.item_dir,
+        EntryKind::File => theme.item_file,
+        EntryKind::Broken | EntryKind::Other => theme.item_broken,
+    }
+    .into()
+}
+
+fn paint_line(line: &Line) -> String {
+    use crate::{config, highlight::Token};
+    use crossterm::style::SetForegroundColor;
+
+    let theme = &config::get().theme;
+
+    line.iter()
+        .map(|(token, s)| {
+            let color = match token {
+                Token::Plain => theme.app_fg,
+                Token::Keyword => theme.item_symlink,
+                Token::Type => theme.item_dir,
+                Token::String => theme.item_file,
+                Token::Number => theme.perm_w,
+                Token::Comment => theme.bar_fg_light,
+            };
+
+            format!("{}{s}", SetForegroundColor(color.into()))
+        })
+        .collect()
+}
+
 #[derive(Hash)]
 pub struct Layout {
     areas: Vec<Rect>,
@@ -164,6 +353,13 @@ impl Layout {
     }
 }
 
+#[derive(Clone, Copy)]
+enum Split {
+    Single,
+    Dual { is_right_focused: bool },
+    Miller,
+}
+
 #[derive(Clone, Copy, Hash)]
 pub struct Rect {
     pub x: u16,
@@ -183,7 +379,13 @@ impl Rect {
     }
 }
 
-fn gen_layout(term_rect: Rect, is_sidemenu_opened: bool) -> Layout {
+fn gen_layout(
+    term_rect: Rect,
+    is_sidemenu_opened: bool,
+    is_preview_opened: bool,
+    is_tabbar_shown: bool,
+    split: Split,
+) -> Layout {
     let mut term_rect = term_rect;
     let mut layout = vec![];
 
@@ -198,6 +400,23 @@ fn gen_layout(term_rect: Rect, is_sidemenu_opened: bool) -> Layout {
         ..term_rect
     };
 
+    let tabbar = if is_tabbar_shown {
+        let tabbar = Rect {
+            height: 1.min(term_rect.height),
+            ..term_rect
+        };
+
+        term_rect = Rect {
+            y: term_rect.y.saturating_add(1),
+            height: term_rect.height.saturating_sub(1),
+            ..term_rect
+        };
+
+        tabbar
+    } else {
+        Rect::empty()
+    };
+
     if is_sidemenu_opened {
         layout.push(Rect {
             width: 20.min(term_rect.width),
@@ -213,29 +432,105 @@ fn gen_layout(term_rect: Rect, is_sidemenu_opened: bool) -> Layout {
         layout.push(Rect::empty());
     }
 
+    let (main, pane) = match split {
+        Split::Single | Split::Miller => (term_rect, Rect::empty()),
+        Split::Dual { is_right_focused } => {
+            let left = Rect {
+                width: term_rect.width / 2,
+                ..term_rect
+            };
+            let right = Rect {
+                x: term_rect.x.saturating_add(left.width),
+                width: term_rect.width.saturating_sub(left.width),
+                ..term_rect
+            };
+
+            let (main, pane) = if is_right_focused {
+                (right, left)
+            } else {
+                (left, right)
+            };
+
+            (
+                main,
+                Rect {
+                    height: pane.height.saturating_sub(1),
+                    ..pane
+                },
+            )
+        }
+    };
+
+    let viewer = Rect {
+        y: main.y.saturating_add(2),
+        height: main.height.saturating_sub(3),
+        ..main
+    };
+
+    let (parent, viewer) = if let Split::Miller = split {
+        let width = viewer.width / 5;
+
+        (
+            Rect { width, ..viewer },
+            Rect {
+                x: viewer.x.saturating_add(width),
+                width: viewer.width.saturating_sub(width),
+                ..viewer
+            },
+        )
+    } else {
+        (Rect::empty(), viewer)
+    };
+
+    let is_preview_shown = match split {
+        Split::Single => is_preview_opened,
+        Split::Dual { .. } => false,
+        Split::Miller => true,
+    };
+
+    let (viewer, preview) = if is_preview_shown {
+        use crate::config;
+
+        let width = (viewer.width as u32 * config::get().preview_width as u32 / 100) as u16;
+
+        (
+            Rect {
+                width: viewer.width.saturating_sub(width),
+                ..viewer
+            },
+            Rect {
+                x: viewer.x.saturating_add(viewer.width.saturating_sub(width)),
+                width,
+                ..viewer
+            },
+        )
+    } else {
+        (viewer, Rect::empty())
+    };
+
     layout.append(&mut vec![
         Rect {
-            height: 1.min(term_rect.height),
-            ..term_rect
-        },
-        Rect {
-            y: term_rect.y.saturating_add(1),
-            height: 1.min(term_rect.height),
-            ..term_rect
+            height: 1.min(main.height),
+            ..main
         },
         Rect {
-            y: term_rect.y.saturating_add(2),
-            height: term_rect.height.saturating_sub(3),
-            ..term_rect
+            y: main.y.saturating_add(1),
+            height: 1.min(main.height),
+            ..main
         },
+        viewer,
         Rect {
-            y: term_rect.height.saturating_sub(1),
+            y: (term_rect.y + term_rect.height).saturating_sub(1),
             height: 1.min(term_rect.height),
             ..term_rect
         },
     ]);
 
     layout.push(log);
+    layout.push(preview);
+    layout.push(tabbar);
+    layout.push(pane);
+    layout.push(parent);
 
     Layout::new(layout)
 }
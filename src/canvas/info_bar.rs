@@ -1,9 +1,11 @@
 use super::Rect;
 use crate::canvas;
-use std::path::PathBuf;
 
 pub(super) struct InfoBar {
-    wd: PathBuf,
+    files_len: usize,
+    hidden_len: usize,
+    filter: String,
+    search_matches: Option<(usize, usize)>,
     cursor_pos: usize,
     file_view_len: usize,
 }
@@ -11,48 +13,84 @@ pub(super) struct InfoBar {
 impl InfoBar {
     pub(super) const ID: u8 = 2;
 
-    pub(super) fn new(wd: PathBuf, cursor_pos: usize, file_view_len: usize) -> Self {
+    pub(super) fn new(
+        files_len: usize,
+        hidden_len: usize,
+        filter: String,
+        search_matches: Option<(usize, usize)>,
+        cursor_pos: usize,
+        file_view_len: usize,
+    ) -> Self {
         Self {
-            wd,
+            files_len,
+            hidden_len,
+            filter,
+            search_matches,
             cursor_pos,
             file_view_len,
         }
     }
 
     pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
-        use crate::misc;
         use std::hash::{DefaultHasher, Hash, Hasher};
 
         let mut hasher = DefaultHasher::new();
 
         layout_hash.hash(&mut hasher);
-        self.wd.hash(&mut hasher);
+        self.files_len.hash(&mut hasher);
+        self.hidden_len.hash(&mut hasher);
+        self.filter.hash(&mut hasher);
+        self.search_matches.hash(&mut hasher);
         self.cursor_pos.hash(&mut hasher);
         self.file_view_len.hash(&mut hasher);
 
-        misc::child_files_len(&self.wd).hash(&mut hasher);
-
         hasher.finish()
     }
 
     pub(super) fn draw(&self, rect: Rect) {
-        use crate::{config, misc};
+        use crate::config;
         use crossterm::style::{SetBackgroundColor, SetForegroundColor};
 
         let theme = &config::get().theme;
         let page = self.cursor_pos / self.file_view_len.max(1) + 1;
-        let files_len = misc::child_files_len(&self.wd);
+        let hidden = if self.hidden_len == 0 {
+            String::new()
+        } else {
+            format!(", {} hidden", self.hidden_len)
+        };
+        let filter = if self.filter.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " {}Filter: {}",
+                SetForegroundColor(theme.bar_fg.into()),
+                self.filter
+            )
+        };
+
+        let search_matches = match self.search_matches {
+            Some((pos, len)) => format!(
+                " {}{}/{} matches",
+                SetForegroundColor(theme.bar_fg.into()),
+                pos,
+                len
+            ),
+            None => String::new(),
+        };
 
         canvas::printin(
             rect,
             (0, 0),
             format!(
-                "{}{} Page {} {}(All {} items){}",
+                "{}{} Page {} {}(All {} items{}){}{}{}",
                 SetBackgroundColor(theme.bar_bg.into()),
                 SetForegroundColor(theme.bar_fg.into()),
                 page,
                 SetForegroundColor(theme.bar_fg_light.into()),
-                files_len,
+                self.files_len,
+                hidden,
+                filter,
+                search_matches,
                 " ".repeat(rect.width.into())
             ),
         );
@@ -0,0 +1,132 @@
+use super::Rect;
+use crate::{
+    canvas,
+    state::{ListItem, ListKind},
+};
+use std::sync::Arc;
+
+pub(super) struct ListPanel {
+    kind: ListKind,
+    items: Arc<Vec<ListItem>>,
+    version: usize,
+    cursor_pos: usize,
+    selection: Vec<usize>,
+}
+
+impl ListPanel {
+    pub(super) fn new(
+        kind: ListKind,
+        items: Arc<Vec<ListItem>>,
+        version: usize,
+        cursor_pos: usize,
+        selection: Vec<usize>,
+    ) -> Self {
+        Self {
+            kind,
+            items,
+            version,
+            cursor_pos,
+            selection,
+        }
+    }
+
+    pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+
+        layout_hash.hash(&mut hasher);
+        self.kind.hash(&mut hasher);
+        self.version.hash(&mut hasher);
+        self.cursor_pos.hash(&mut hasher);
+        self.selection.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    pub(super) fn draw(&self, rect: Rect) {
+        use crate::config;
+        use crossterm::style::{SetBackgroundColor, SetForegroundColor};
+
+        let theme = &config::get().theme;
+        let title = match self.kind {
+            ListKind::Trash => "Trash",
+            ListKind::Jobs => "Jobs",
+            ListKind::JobErrors(_) => "Job errors",
+            ListKind::Find => "Find",
+            ListKind::Grep => "Grep",
+            ListKind::Marks => "Marks",
+            ListKind::History => "History",
+        };
+
+        canvas::printin(
+            rect,
+            (0, 0),
+            format!(
+                "{}{} {} {}({} items){}",
+                SetBackgroundColor(theme.bar_bg.into()),
+                SetForegroundColor(theme.bar_fg.into()),
+                title,
+                SetForegroundColor(theme.bar_fg_light.into()),
+                self.items.len(),
+                " ".repeat(rect.width.into())
+            ),
+        );
+
+        let page_size = rect.height.saturating_sub(1) as usize;
+
+        if page_size == 0 {
+            return;
+        }
+
+        let page_index = self.cursor_pos / page_size;
+
+        for rel_i in 0..page_size {
+            let abs_i = rel_i + page_size * page_index;
+            let row = rel_i as u16 + 1;
+
+            let Some(item) = self.items.get(abs_i) else {
+                canvas::printin(
+                    rect,
+                    (0, row),
+                    format!(
+                        "{}{}{}{}",
+                        SetBackgroundColor(theme.app_bg.into()),
+                        SetForegroundColor(theme.bar_fg.into()),
+                        if abs_i == 0 { "> | Empty" } else { "" },
+                        " ".repeat(rect.width.into())
+                    ),
+                );
+
+                continue;
+            };
+
+            let is_cursor_pos = self.cursor_pos == abs_i;
+            let under_label = if self.selection.contains(&abs_i) {
+                theme.item_bg_select
+            } else if is_cursor_pos {
+                theme.item_bg_cursor
+            } else {
+                theme.app_bg
+            };
+
+            canvas::printin(
+                rect,
+                (0, row),
+                format!(
+                    "{}{}{} | {}{} {}{}{}{}{}",
+                    SetBackgroundColor(theme.app_bg.into()),
+                    SetForegroundColor(theme.app_fg.into()),
+                    if is_cursor_pos { ">" } else { " " },
+                    SetForegroundColor(theme.item_parts_lmd.into()),
+                    item.detail,
+                    SetBackgroundColor(under_label.into()),
+                    SetForegroundColor(theme.item_file.into()),
+                    item.label,
+                    SetBackgroundColor(theme.app_bg.into()),
+                    " ".repeat(rect.width.into())
+                ),
+            );
+        }
+    }
+}
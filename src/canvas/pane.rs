@@ -0,0 +1,43 @@
+use super::{Rect, info_bar::InfoBar, pwd::Working, viewer::Viewer};
+
+pub(super) struct Pane {
+    working: Working,
+    infobar: InfoBar,
+    viewer: Viewer,
+}
+
+impl Pane {
+    pub(super) const ID: u8 = 8;
+
+    pub(super) fn new(working: Working, infobar: InfoBar, viewer: Viewer) -> Self {
+        Self {
+            working,
+            infobar,
+            viewer,
+        }
+    }
+
+    pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+
+        self.working.make_hash(layout_hash).hash(&mut hasher);
+        self.infobar.make_hash(layout_hash).hash(&mut hasher);
+        self.viewer.make_hash(layout_hash).hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    pub(super) fn draw(&self, rect: Rect) {
+        let row = |y: u16, height: u16| Rect {
+            y: rect.y.saturating_add(y),
+            height: height.min(rect.height.saturating_sub(y)),
+            ..rect
+        };
+
+        self.working.draw(row(0, 1));
+        self.infobar.draw(row(1, 1));
+        self.viewer.draw(row(2, rect.height.saturating_sub(2)));
+    }
+}
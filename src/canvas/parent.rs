@@ -0,0 +1,100 @@
+use super::Rect;
+use crate::canvas;
+use std::path::PathBuf;
+
+pub(super) struct Parent {
+    wd: PathBuf,
+    show_hidden: bool,
+    version: usize,
+}
+
+impl Parent {
+    pub(super) const ID: u8 = 9;
+
+    pub(super) fn new(wd: PathBuf, show_hidden: bool, version: usize) -> Self {
+        Self {
+            wd,
+            show_hidden,
+            version,
+        }
+    }
+
+    pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+
+        layout_hash.hash(&mut hasher);
+        self.wd.hash(&mut hasher);
+        self.show_hidden.hash(&mut hasher);
+        self.version.hash(&mut hasher);
+
+        if let Some(parent) = self.wd.parent()
+            && let Ok(metadata) = parent.metadata()
+        {
+            metadata.modified().ok().hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    pub(super) fn draw(&self, rect: Rect) {
+        use crate::{
+            component::{self, EntryFilter},
+            config,
+        };
+        use crossterm::style::{SetBackgroundColor, SetForegroundColor};
+
+        let theme = &config::get().theme;
+        let page_size = rect.height as usize;
+
+        if page_size == 0 {
+            return;
+        }
+
+        let filter = EntryFilter {
+            show_hidden: self.show_hidden,
+            pattern: String::new(),
+        };
+        let siblings = self
+            .wd
+            .parent()
+            .map(|parent| filter.apply(&component::read_entries(parent)))
+            .unwrap_or_default();
+        let current = siblings
+            .iter()
+            .position(|entry| entry.path == self.wd)
+            .unwrap_or(0);
+        let page_index = current / page_size;
+
+        for rel_i in 0..page_size {
+            let abs_i = rel_i + page_size * page_index;
+
+            let row = match siblings.get(abs_i) {
+                Some(entry) => format!(
+                    "{}{} {}{}{}{}",
+                    SetBackgroundColor(theme.app_bg.into()),
+                    SetForegroundColor(canvas::kind_color(entry.kind)),
+                    SetBackgroundColor(
+                        if entry.path == self.wd {
+                            theme.item_bg_cursor
+                        } else {
+                            theme.app_bg
+                        }
+                        .into()
+                    ),
+                    entry.name,
+                    SetBackgroundColor(theme.app_bg.into()),
+                    " ".repeat(rect.width.into())
+                ),
+                None => format!(
+                    "{}{}",
+                    SetBackgroundColor(theme.app_bg.into()),
+                    " ".repeat(rect.width.into())
+                ),
+            };
+
+            canvas::printin(rect, (0, rel_i as u16), row);
+        }
+    }
+}
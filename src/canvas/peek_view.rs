@@ -0,0 +1,85 @@
+use super::Rect;
+use crate::{canvas, highlight::Line};
+use std::{path::PathBuf, sync::Arc};
+
+pub(super) struct PeekView {
+    target: PathBuf,
+    info: String,
+    lines: Arc<Vec<Line>>,
+    scroll: usize,
+}
+
+impl PeekView {
+    pub(super) fn new(target: PathBuf, info: String, lines: Arc<Vec<Line>>, scroll: usize) -> Self {
+        Self {
+            target,
+            info,
+            lines,
+            scroll,
+        }
+    }
+
+    pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+
+        layout_hash.hash(&mut hasher);
+        self.target.hash(&mut hasher);
+        self.info.hash(&mut hasher);
+        Arc::as_ptr(&self.lines).hash(&mut hasher);
+        self.scroll.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    pub(super) fn draw(&self, rect: Rect) {
+        use crate::{config, misc};
+        use crossterm::style::{SetBackgroundColor, SetForegroundColor};
+
+        let theme = &config::get().theme;
+
+        canvas::printin(
+            rect,
+            (0, 0),
+            format!(
+                "{}{} {} {}({}, {}/{}){}",
+                SetBackgroundColor(theme.bar_bg.into()),
+                SetForegroundColor(theme.bar_fg.into()),
+                misc::entry_name(&self.target),
+                SetForegroundColor(theme.bar_fg_light.into()),
+                self.info,
+                (self.scroll + 1).min(self.lines.len()),
+                self.lines.len(),
+                " ".repeat(rect.width.into())
+            ),
+        );
+
+        let number_width = self.lines.len().to_string().len();
+
+        for rel_i in 0..rect.height.saturating_sub(1) {
+            let abs_i = self.scroll + rel_i as usize;
+
+            let row = match self.lines.get(abs_i) {
+                Some(line) => format!(
+                    "{}{:>number_width$} {}",
+                    SetForegroundColor(theme.bar_fg_light.into()),
+                    abs_i + 1,
+                    canvas::paint_line(line)
+                ),
+                None => String::new(),
+            };
+
+            canvas::printin(
+                rect,
+                (0, rel_i + 1),
+                format!(
+                    "{}{}{}",
+                    SetBackgroundColor(theme.app_bg.into()),
+                    row,
+                    " ".repeat(rect.width.into())
+                ),
+            );
+        }
+    }
+}
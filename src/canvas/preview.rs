@@ -0,0 +1,212 @@
+use super::Rect;
+use crate::canvas;
+use std::path::{Path, PathBuf};
+
+const READ_LIMIT: u64 = 64 * 1024;
+
+pub(super) struct Preview {
+    target: Option<PathBuf>,
+    version: usize,
+    show_hidden: bool,
+}
+
+impl Preview {
+    pub(super) const ID: u8 = 6;
+
+    pub(super) fn new(target: Option<PathBuf>, version: usize, show_hidden: bool) -> Self {
+        Self {
+            target,
+            version,
+            show_hidden,
+        }
+    }
+
+    pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+
+        layout_hash.hash(&mut hasher);
+        self.target.hash(&mut hasher);
+        self.version.hash(&mut hasher);
+        self.show_hidden.hash(&mut hasher);
+
+        if let Some(ref target) = self.target
+            && let Ok(metadata) = target.symlink_metadata()
+        {
+            metadata.modified().ok().hash(&mut hasher);
+            metadata.len().hash(&mut hasher);
+        }
+
+        hasher.finish()
+    }
+
+    pub(super) fn draw(&self, rect: Rect) {
+        use crate::{config, misc};
+        use crossterm::style::{SetBackgroundColor, SetForegroundColor};
+
+        let theme = &config::get().theme;
+
+        canvas::printin(
+            rect,
+            (0, 0),
+            format!(
+                "{}{} {}{}",
+                SetBackgroundColor(theme.bar_bg.into()),
+                SetForegroundColor(theme.bar_fg.into()),
+                self.target
+                    .as_ref()
+                    .map(|target| misc::entry_name(target))
+                    .unwrap_or_default(),
+                " ".repeat(rect.width.into())
+            ),
+        );
+
+        let body_len = rect.height.saturating_sub(1) as usize;
+        let lines = match self.target {
+            Some(ref target) if target.is_dir() => dir_lines(target, body_len, self.show_hidden),
+            Some(ref target) => {
+                text_lines(target, body_len).unwrap_or_else(|| metadata_lines(target))
+            }
+            None => vec![],
+        };
+
+        for rel_i in 0..body_len {
+            canvas::printin(
+                rect,
+                (0, rel_i as u16 + 1),
+                format!(
+                    "{}{}│ {}{}{}",
+                    SetBackgroundColor(theme.app_bg.into()),
+                    SetForegroundColor(theme.bar_bg.into()),
+                    SetForegroundColor(theme.app_fg.into()),
+                    lines.get(rel_i).map(String::as_str).unwrap_or(""),
+                    " ".repeat(rect.width.into())
+                ),
+            );
+        }
+    }
+}
+
+fn dir_lines(dir: &Path, len: usize, show_hidden: bool) -> Vec<String> {
+    use crate::{
+        component::{self, EntryFilter},
+        config,
+    };
+    use crossterm::style::SetForegroundColor;
+
+    let theme = &config::get().theme;
+    let filter = EntryFilter {
+        show_hidden,
+        pattern: String::new(),
+    };
+    let children = filter.apply(&component::read_entries(dir));
+
+    if children.is_empty() {
+        return vec![format!(
+            "{}Empty",
+            SetForegroundColor(theme.bar_fg_light.into())
+        )];
+    }
+
+    children
+        .iter()
+        .take(len)
+        .map(|child| {
+            format!(
+                "{}{}",
+                SetForegroundColor(canvas::kind_color(child.kind)),
+                child.name
+            )
+        })
+        .collect()
+}
+
+fn text_lines(path: &Path, len: usize) -> Option<Vec<String>> {
+    use crate::highlight;
+
+    let text = highlight::decode_text(&read_head(path)?)?;
+    let syntax = highlight::detect(path, &text);
+
+    Some(
+        highlight::highlight(syntax, &text)
+            .iter()
+            .take(len)
+            .map(canvas::paint_line)
+            .collect(),
+    )
+}
+
+fn read_head(path: &Path) -> Option<Vec<u8>> {
+    use std::{fs::File, io::Read};
+
+    if !path.is_file() {
+        return None;
+    }
+
+    let mut buf = vec![];
+
+    File::open(path)
+        .ok()?
+        .take(READ_LIMIT)
+        .read_to_end(&mut buf)
+        .ok()?;
+
+    Some(buf)
+}
+
+fn metadata_lines(path: &Path) -> Vec<String> {
+    use crate::config;
+    use chrono::{DateTime, Local};
+    use crossterm::style::SetForegroundColor;
+    use si_scale::helpers;
+    use std::{os::unix::fs::MetadataExt, time::SystemTime};
+
+    let theme = &config::get().theme;
+
+    let Ok(metadata) = path.symlink_metadata() else {
+        return vec![format!(
+            "{}Permission denied",
+            SetForegroundColor(theme.item_broken.into())
+        )];
+    };
+
+    let date = |time: std::io::Result<SystemTime>| {
+        time.map(|time| {
+            DateTime::<Local>::from(time)
+                .format("%Y-%m-%d %H:%M:%S")
+                .to_string()
+        })
+        .unwrap_or(String::from("-"))
+    };
+
+    let file_type = match metadata.file_type() {
+        ty if ty.is_symlink() => "symlink",
+        ty if ty.is_file() => "file",
+        ty if ty.is_dir() => "directory",
+        _ => "other",
+    };
+
+    let mut rows = vec![
+        ("Type", file_type.to_string()),
+        ("Size", helpers::bytes1(metadata.len() as f64)),
+        ("Mode", format!("{:o}", metadata.mode() & 0o7777)),
+        ("Owner", format!("{}:{}", metadata.uid(), metadata.gid())),
+        ("Modified", date(metadata.modified())),
+        ("Accessed", date(metadata.accessed())),
+    ];
+
+    if let Ok(link) = path.read_link() {
+        rows.push(("Link", link.to_string_lossy().to_string()));
+    }
+
+    rows.into_iter()
+        .map(|(key, value)| {
+            format!(
+                "{}{key:<9}{}{value}",
+                SetForegroundColor(theme.item_parts_lmd.into()),
+                SetForegroundColor(theme.app_fg.into()),
+            )
+        })
+        .collect()
+}
@@ -1,15 +1,25 @@
 use super::Rect;
-use crate::canvas;
+use crate::{canvas, component::Mark, config::MenuElement};
 
 pub(super) struct Sidemenu {
     cursor_pos: usize,
+    elements: Vec<MenuElement>,
+    marks: Vec<(char, Mark)>,
 }
 
 impl Sidemenu {
     pub(super) const ID: u8 = 0;
 
-    pub(super) fn new(cursor_pos: usize) -> Self {
-        Self { cursor_pos }
+    pub(super) fn new(
+        cursor_pos: usize,
+        elements: Vec<MenuElement>,
+        marks: Vec<(char, Mark)>,
+    ) -> Self {
+        Self {
+            cursor_pos,
+            elements,
+            marks,
+        }
     }
 
     pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
@@ -19,16 +29,28 @@ impl Sidemenu {
 
         layout_hash.hash(&mut hasher);
         self.cursor_pos.hash(&mut hasher);
+        self.elements.hash(&mut hasher);
+        self.marks.hash(&mut hasher);
 
         hasher.finish()
     }
 
     pub(super) fn draw(&self, rect: Rect) {
-        use crate::config;
+        use crate::{config, misc};
         use crossterm::style::{SetBackgroundColor, SetForegroundColor};
 
         let config = config::get();
         let theme = &config.theme;
+        let tags = self
+            .elements
+            .iter()
+            .map(|element| element.tag.clone())
+            .chain(
+                self.marks
+                    .iter()
+                    .map(|(c, mark)| format!("'{c} {}", misc::entry_name(&mark.dir))),
+            )
+            .collect::<Vec<_>>();
 
         let non_sep_width = rect.width.saturating_sub(1) as usize;
 
@@ -57,8 +79,8 @@ impl Sidemenu {
         for i in 2..rect.height.saturating_sub(1) {
             let rel_i = i.saturating_sub(2) as usize;
 
-            match config.menu_elements.get(rel_i) {
-                Some(element) => {
+            match tags.get(rel_i) {
+                Some(tag) => {
                     let cursor = if rel_i == self.cursor_pos { ">" } else { " " };
                     let under_cursor = if rel_i == self.cursor_pos {
                         SetBackgroundColor(theme.item_bg_cursor.into())
@@ -76,9 +98,9 @@ impl Sidemenu {
                             cursor,
                             under_cursor,
                             SetForegroundColor(theme.item_sidemenu.into()),
-                            element.tag,
+                            tag,
                             SetBackgroundColor(theme.app_bg.into()),
-                            " ".repeat(non_sep_width.saturating_sub(element.tag.len() + 6)),
+                            " ".repeat(non_sep_width.saturating_sub(tag.len() + 6)),
                         ),
                     );
                 }
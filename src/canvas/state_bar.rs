@@ -1,16 +1,21 @@
 use super::Rect;
-use crate::{canvas, state::Mode};
+use crate::{canvas, job::Transfer, state::Mode};
 
 pub(super) struct StateBar {
     mode: Mode,
-    procs: usize,
+    jobs: usize,
+    transfer: Option<Transfer>,
 }
 
 impl StateBar {
     pub(super) const ID: u8 = 4;
 
-    pub(super) fn new(mode: Mode, procs: usize) -> Self {
-        Self { mode, procs }
+    pub(super) fn new(mode: Mode, jobs: usize, transfer: Option<Transfer>) -> Self {
+        Self {
+            mode,
+            jobs,
+            transfer,
+        }
     }
 
     pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
@@ -20,7 +25,8 @@ impl StateBar {
 
         layout_hash.hash(&mut hasher);
         self.mode.hash(&mut hasher);
-        self.procs.hash(&mut hasher);
+        self.jobs.hash(&mut hasher);
+        self.transfer.hash(&mut hasher);
 
         hasher.finish()
     }
@@ -37,19 +43,47 @@ impl StateBar {
             Mode::Input => format!("{} INPUT ", SetBackgroundColor(theme.mode_input.into())),
             Mode::Search => format!("{} SEARCH ", SetBackgroundColor(theme.mode_search.into())),
             Mode::Menu => format!("{} MENU ", SetBackgroundColor(theme.mode_menu.into())),
+            Mode::List => format!("{} LIST ", SetBackgroundColor(theme.mode_menu.into())),
+            Mode::Peek => format!("{} PEEK ", SetBackgroundColor(theme.mode_normal.into())),
+            Mode::Filter => format!("{} FILTER ", SetBackgroundColor(theme.mode_search.into())),
         };
 
+        let transfer = self
+            .transfer
+            .map(|transfer| format!(" | {}", format_transfer(transfer)))
+            .unwrap_or_default();
+
         canvas::printin(
             rect,
             (0, 0),
             format!(
-                "{}{}{} {} procs running{}",
+                "{}{}{} {} jobs running{}{}",
                 SetForegroundColor(theme.bar_fg.into()),
                 current_mode,
                 SetBackgroundColor(theme.bar_bg.into()),
-                self.procs,
+                self.jobs,
+                transfer,
                 " ".repeat(rect.width.into())
             ),
         );
     }
 }
+
+fn format_transfer(transfer: Transfer) -> String {
+    use si_scale::helpers;
+
+    let percent = transfer.bytes_done * 100 / transfer.bytes_total.max(1);
+    let eta = transfer
+        .eta
+        .map(|secs| format!("{:02}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60))
+        .unwrap_or(String::from("--:--:--"));
+
+    format!(
+        "{} / {} ({percent}%) {}/{} files {}/s ETA {eta}",
+        helpers::bytes1(transfer.bytes_done as f64),
+        helpers::bytes1(transfer.bytes_total as f64),
+        transfer.files_done,
+        transfer.files_total,
+        helpers::bytes1(transfer.rate as f64),
+    )
+}
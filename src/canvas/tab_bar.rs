@@ -0,0 +1,71 @@
+use super::Rect;
+use crate::canvas;
+use std::path::PathBuf;
+
+pub(super) struct TabBar {
+    dirs: Vec<PathBuf>,
+    current: usize,
+}
+
+impl TabBar {
+    pub(super) const ID: u8 = 7;
+
+    pub(super) fn new(dirs: Vec<PathBuf>, current: usize) -> Self {
+        Self { dirs, current }
+    }
+
+    pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
+        use std::hash::{DefaultHasher, Hash, Hasher};
+
+        let mut hasher = DefaultHasher::new();
+
+        layout_hash.hash(&mut hasher);
+        self.dirs.hash(&mut hasher);
+        self.current.hash(&mut hasher);
+
+        hasher.finish()
+    }
+
+    pub(super) fn draw(&self, rect: Rect) {
+        use crate::{config, misc};
+        use crossterm::style::{SetBackgroundColor, SetForegroundColor};
+
+        let theme = &config::get().theme;
+
+        let tabs = self
+            .dirs
+            .iter()
+            .enumerate()
+            .map(|(i, dir)| {
+                let name = match misc::entry_name(dir) {
+                    name if name.is_empty() => String::from("/"),
+                    name => name,
+                };
+                let (bg, fg) = if i == self.current {
+                    (theme.item_bg_cursor, theme.pwd_pickouted)
+                } else {
+                    (theme.bar_bg, theme.bar_fg_light)
+                };
+
+                format!(
+                    "{}{} {} {} ",
+                    SetBackgroundColor(bg.into()),
+                    SetForegroundColor(fg.into()),
+                    i + 1,
+                    name
+                )
+            })
+            .collect::<String>();
+
+        canvas::printin(
+            rect,
+            (0, 0),
+            format!(
+                "{}{}{}",
+                tabs,
+                SetBackgroundColor(theme.bar_bg.into()),
+                " ".repeat(rect.width.into())
+            ),
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use super::Rect;
-use crate::canvas;
-use std::path::{Path, PathBuf};
+use crate::{canvas, component::Entry};
+use std::sync::Arc;
 
 pub(super) struct Viewer {
-    wd: PathBuf,
+    entries: Arc<Vec<Entry>>,
+    version: usize,
     cursor_pos: usize,
     selection: Vec<usize>,
     grep: String,
@@ -15,8 +16,10 @@ pub(super) struct Viewer {
 impl Viewer {
     pub(super) const ID: u8 = 3;
 
+    #[allow(clippy::too_many_arguments)]
     pub(super) fn new(
-        wd: PathBuf,
+        entries: Arc<Vec<Entry>>,
+        version: usize,
         cursor_pos: usize,
         selection: Vec<usize>,
         grep: String,
@@ -25,7 +28,8 @@ impl Viewer {
         input_cursor: usize,
     ) -> Self {
         Self {
-            wd,
+            entries,
+            version,
             cursor_pos,
             selection,
             grep,
@@ -36,13 +40,12 @@ impl Viewer {
     }
 
     pub(super) fn make_hash(&self, layout_hash: u64) -> u64 {
-        use crate::misc;
         use std::hash::{DefaultHasher, Hash, Hasher};
 
         let mut hasher = DefaultHasher::new();
 
         layout_hash.hash(&mut hasher);
-        self.wd.hash(&mut hasher);
+        self.version.hash(&mut hasher);
         self.cursor_pos.hash(&mut hasher);
         self.selection.hash(&mut hasher);
         self.grep.hash(&mut hasher);
@@ -50,15 +53,10 @@ impl Viewer {
         self.input_buf.hash(&mut hasher);
         self.input_cursor.hash(&mut hasher);
 
-        misc::child_files(&self.wd).hash(&mut hasher);
-
         hasher.finish()
     }
 
     pub(super) fn draw(&self, rect: Rect) {
-        use crate::misc;
-
-        let wd = &self.wd;
         let page_size = rect.height as usize;
 
         if page_size == 0 {
@@ -66,27 +64,27 @@ impl Viewer {
         }
 
         let page_index = self.cursor_pos / page_size;
-        let items = pagenate(wd, page_size, page_index).unwrap_or_default();
+        let items = self
+            .entries
+            .chunks(page_size)
+            .nth(page_index)
+            .unwrap_or_default();
 
-        let (tag, ctx) = self
+        let is_input_active = self
             .input_tag
             .as_ref()
-            .and_then(|tag| tag.split_once(":"))
-            .unzip();
-
-        let is_input_active = tag == Some("RenameThisItem");
-        let input_idx = ctx.and_then(|ctx| ctx.parse::<usize>().ok());
+            .is_some_and(|tag| tag.starts_with("RenameThisItem:"));
 
         for rel_i in 0..page_size {
             let abs_i = rel_i + page_size * page_index;
 
             match items.get(rel_i) {
-                Some(_) if is_input_active && input_idx == Some(abs_i) => {
+                Some(_) if is_input_active && self.cursor_pos == abs_i => {
                     if let Some(ref input_buf) = self.input_buf {
                         render_input_row(rect, rel_i, input_buf, self.input_cursor);
                     }
                 }
-                Some(item) if &misc::entry_name(item) == ".ep.ed" => {
+                Some(item) if item.name == ".ep.ed" => {
                     if let Some(ref input_buf) = self.input_buf {
                         render_input_row(rect, rel_i, input_buf, self.input_cursor);
                     }
@@ -105,28 +103,17 @@ impl Viewer {
     }
 }
 
-fn pagenate(wd: &Path, page_size: usize, page_index: usize) -> Option<Vec<PathBuf>> {
-    use crate::misc;
-
-    let usable_paths = misc::sorted_child_files(wd).to_vec();
-
-    let pages = usable_paths.chunks(page_size).collect::<Vec<_>>();
-
-    pages.get(page_index).map(|paths| paths.to_vec())
-}
-
 fn render_item_row(
     rect: Rect,
     index: usize,
-    item: &Path,
+    item: &Entry,
     is_cursor_pos: bool,
     is_selected: bool,
     grep: &str,
 ) {
-    use crate::{config, misc};
+    use crate::{component::EntryKind, config, misc};
     use chrono::{DateTime, Local};
     use crossterm::style::{SetBackgroundColor, SetForegroundColor};
-    use regex::Regex;
     use si_scale::helpers;
     use std::os::unix::fs::PermissionsExt;
 
@@ -135,15 +122,15 @@ fn render_item_row(
     let file_type = format!(
         "{}{}",
         SetForegroundColor(theme.perm_ty.into()),
-        match item {
-            item if item.is_symlink() => 'l',
-            item if item.is_dir() => 'd',
-            item if item.is_file() => '-',
+        match item.kind {
+            _ if item.link.is_some() => 'l',
+            EntryKind::Dir => 'd',
+            EntryKind::File => '-',
             _ => 'o',
         }
     );
 
-    let Ok(metadata) = item.symlink_metadata() else {
+    let Some(ref metadata) = item.metadata else {
         canvas::printin(
             rect,
             (0, index as u16),
@@ -211,25 +198,15 @@ fn render_item_row(
     };
     let file_name = format!(
         "{}{}{}",
-        SetForegroundColor(
-            match item {
-                path if !path.exists() => theme.item_broken,
-                path if path.is_symlink() => theme.item_symlink,
-                path if path.is_dir() => theme.item_dir,
-                path if path.is_file() => theme.item_file,
-                _ => theme.item_broken,
-            }
-            .into()
-        ),
+        SetForegroundColor(canvas::kind_color(item.kind)),
         'n: {
-            let reg = Regex::new(grep);
-            let name = misc::entry_name(item);
+            let name = item.name.clone();
 
             if grep.is_empty() {
                 break 'n name;
             }
 
-            let Ok(regex) = reg else {
+            let Some(regex) = misc::smart_regex(grep) else {
                 break 'n name;
             };
 
@@ -250,9 +227,9 @@ fn render_item_row(
                 None => name,
             }
         },
-        match item.read_link() {
-            Ok(link) => format!(" -> {}", link.to_string_lossy()),
-            Err(_) => "".to_string(),
+        match item.link {
+            Some(ref link) => format!(" -> {}", link.to_string_lossy()),
+            None => "".to_string(),
         }
     );
 
@@ -37,10 +37,19 @@ pub fn clip<S: AsRef<str>>(s: S) -> io::Result<()> {
 }
 
 pub fn clip_native<S: AsRef<str>>(s: S, ty: &str) -> io::Result<()> {
+    clip_native_all(&[(s.as_ref(), ty)])
+}
+
+// wl-copy and xclip serve only one type per selection, so only the first one is offered there.
+pub fn clip_native_all(items: &[(&str, &str)]) -> io::Result<()> {
     let wsys = WindowSystem::load();
 
+    let Some(&(s, ty)) = items.first() else {
+        return Ok(());
+    };
+
     match wsys {
-        WindowSystem::Macos => clip_macos(s, ty),
+        WindowSystem::Macos => clip_macos(items),
         WindowSystem::Wayland => clip_wayland(s, ty),
         WindowSystem::X11 => clip_x11(s, ty),
     }
@@ -133,9 +142,14 @@ fn read_x11(ty: &str) -> io::Result<String> {
     Ok(String::from(output))
 }
 
-fn clip_macos<S: AsRef<str>>(s: S, ty: &str) -> io::Result<()> {
+fn clip_macos(items: &[(&str, &str)]) -> io::Result<()> {
     use std::process::{Command, Stdio};
 
+    let sets = items
+        .iter()
+        .map(|(s, ty)| format!(r#"pb.setStringForType($(ObjC.unwrap("{s}")), mimeToUTI("{ty}"));"#))
+        .collect::<Vec<_>>()
+        .join("\n");
     let script = format!(
         r#"""
         ObjC.import("CoreServices");
@@ -147,11 +161,8 @@ fn clip_macos<S: AsRef<str>>(s: S, ty: &str) -> io::Result<()> {
         }}
         let pb = $.NSPasteboard.generalPasteboard;
         pb.clearContents;
-        let str = $(ObjC.unwrap("{}"));
-        pb.setStringForType(str, mimeToUTI("{}"));
-        """#,
-        s.as_ref(),
-        ty
+        {sets}
+        """#
     );
 
     Command::new("osascript")
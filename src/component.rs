@@ -1,9 +1,19 @@
+mod conflict;
 mod cursor;
 mod cursor_cache;
 mod input;
+mod journal;
+mod jumplist;
+mod marks;
 mod selection;
+mod snapshot;
 
+pub use conflict::{ConflictQueue, PastePlan, Resolution};
 pub use cursor::Cursor;
 pub use cursor_cache::CursorCache;
 pub use input::Input;
-pub use selection::Selection;
+pub use journal::{Journal, Operation, Stamp};
+pub use jumplist::{Jump, Jumplist};
+pub use marks::{Mark, Marks};
+pub use selection::{PathSelection, Selection};
+pub use snapshot::{DirSnapshot, Entry, EntryFilter, EntryKind, read_entries};
@@ -0,0 +1,91 @@
+use std::{path::PathBuf, sync::RwLock};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum Resolution {
+    Overwrite,
+    Skip,
+    Rename,
+    OverwriteIfNewer,
+}
+
+impl Resolution {
+    pub fn from_key(key: char) -> Option<(Self, bool)> {
+        let resolution = match key.to_ascii_lowercase() {
+            'o' => Resolution::Overwrite,
+            's' => Resolution::Skip,
+            'r' => Resolution::Rename,
+            'n' => Resolution::OverwriteIfNewer,
+            _ => return None,
+        };
+
+        Some((resolution, key.is_ascii_uppercase()))
+    }
+}
+
+pub struct PastePlan {
+    pub dir: PathBuf,
+    pub is_cut: bool,
+    pub is_clipped: bool,
+    pub items: Vec<PathBuf>,
+    pub conflicts: Vec<(PathBuf, Option<Resolution>)>,
+}
+
+impl PastePlan {
+    pub fn resolution_of(&self, item: &PathBuf) -> Option<Resolution> {
+        self.conflicts
+            .iter()
+            .find(|(path, _)| path == item)
+            .and_then(|(_, resolution)| *resolution)
+    }
+
+    pub fn resolve_all(&mut self, resolution: Resolution) {
+        self.conflicts
+            .iter_mut()
+            .filter(|(_, res)| res.is_none())
+            .for_each(|(_, res)| *res = Some(resolution));
+    }
+}
+
+pub struct ConflictQueue {
+    plan: RwLock<Option<PastePlan>>,
+}
+
+impl ConflictQueue {
+    pub fn new() -> Self {
+        Self {
+            plan: RwLock::new(None),
+        }
+    }
+
+    pub fn start(&self, plan: PastePlan) {
+        *self.plan.write().unwrap() = Some(plan);
+    }
+
+    pub fn current(&self) -> Option<(usize, PathBuf)> {
+        self.plan.read().unwrap().as_ref().and_then(|plan| {
+            plan.conflicts
+                .iter()
+                .enumerate()
+                .find(|(_, (_, res))| res.is_none())
+                .map(|(i, (path, _))| (i, path.clone()))
+        })
+    }
+
+    pub fn resolve(&self, resolution: Resolution, apply_all: bool) {
+        let mut lock = self.plan.write().unwrap();
+
+        let Some(plan) = lock.as_mut() else {
+            return;
+        };
+
+        if apply_all {
+            plan.resolve_all(resolution);
+        } else if let Some((_, res)) = plan.conflicts.iter_mut().find(|(_, res)| res.is_none()) {
+            *res = Some(resolution);
+        }
+    }
+
+    pub fn take(&self) -> Option<PastePlan> {
+        self.plan.write().unwrap().take()
+    }
+}
@@ -56,14 +56,6 @@ impl Cursor {
         self.swap_id(self.current().saturating_sub(val));
     }
 
-    pub fn shift_loop_p(&self, val: usize) {
-        if val + self.current() < self.size.load(Ordering::Relaxed) {
-            self.shift_p(val);
-        } else {
-            self.swap_id((self.current() + val) - self.size.load(Ordering::Relaxed));
-        }
-    }
-
     pub fn reset(&self) {
         self.index.swap(0, Ordering::Relaxed);
     }
@@ -45,6 +45,16 @@ impl CursorCache {
         }
     }
 
+    pub fn replace(&self, other: CursorCache) -> CursorCache {
+        use std::mem;
+
+        let inner = other.inner.into_inner().unwrap();
+
+        CursorCache {
+            inner: RwLock::new(mem::replace(&mut *self.inner.write().unwrap(), inner)),
+        }
+    }
+
     pub fn reset(&self) {
         *self.inner.write().unwrap() = None;
     }
@@ -0,0 +1,115 @@
+use std::{
+    path::{Path, PathBuf},
+    sync::RwLock,
+    time::SystemTime,
+};
+
+const JOURNAL_LIMIT: usize = 100;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct Stamp {
+    entries: usize,
+    len: u64,
+    modified: Option<SystemTime>,
+}
+
+impl Stamp {
+    pub fn of(path: &Path) -> Option<Self> {
+        use walkdir::WalkDir;
+
+        let mut stamp = Stamp {
+            entries: 0,
+            len: 0,
+            modified: None,
+        };
+
+        for entry in WalkDir::new(path) {
+            let metadata = entry.ok()?.metadata().ok()?;
+
+            stamp.entries += 1;
+
+            if metadata.is_file() {
+                stamp.len += metadata.len();
+            }
+
+            stamp.modified = stamp.modified.max(metadata.modified().ok());
+        }
+
+        Some(stamp)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum Operation {
+    Create {
+        path: PathBuf,
+        is_dir: bool,
+        stamp: Option<Stamp>,
+    },
+    Rename {
+        from: PathBuf,
+        to: PathBuf,
+    },
+    Delete {
+        path: PathBuf,
+        trashed: Option<PathBuf>,
+    },
+    Copy {
+        from: PathBuf,
+        to: PathBuf,
+        stamp: Option<Stamp>,
+    },
+}
+
+pub struct Journal {
+    undo: RwLock<Vec<Vec<Operation>>>,
+    redo: RwLock<Vec<Vec<Operation>>>,
+}
+
+impl Journal {
+    pub fn new() -> Self {
+        Self {
+            undo: RwLock::new(vec![]),
+            redo: RwLock::new(vec![]),
+        }
+    }
+
+    pub fn record(&self, step: Vec<Operation>) {
+        if step.is_empty() {
+            return;
+        }
+
+        self.push_undo(step);
+        self.redo.write().unwrap().clear();
+    }
+
+    pub fn push_undo(&self, step: Vec<Operation>) {
+        if step.is_empty() {
+            return;
+        }
+
+        let mut undo = self.undo.write().unwrap();
+
+        undo.push(step);
+
+        if undo.len() > JOURNAL_LIMIT {
+            undo.remove(0);
+        }
+    }
+
+    pub fn push_redo(&self, step: Vec<Operation>) {
+        if step.is_empty() {
+            return;
+        }
+
+        self.redo.write().unwrap().push(step);
+    }
+
+    pub fn take_undo(&self) -> Option<Vec<Operation>> {
+        self.undo.write().unwrap().pop()
+    }
+
+    pub fn take_redo(&self) -> Option<Vec<Operation>> {
+        self.redo.write().unwrap().pop()
+    }
+}
@@ -0,0 +1,82 @@
+use std::{path::PathBuf, sync::RwLock};
+
+const JUMPLIST_LIMIT: usize = 100;
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Jump {
+    pub dir: PathBuf,
+    pub focus: Option<PathBuf>,
+}
+
+pub struct Jumplist {
+    jumps: RwLock<Vec<Jump>>,
+    pos: RwLock<usize>,
+}
+
+impl Jumplist {
+    pub fn new() -> Self {
+        Self {
+            jumps: RwLock::new(vec![]),
+            pos: RwLock::new(0),
+        }
+    }
+
+    pub fn record(&self, from: Jump) {
+        let mut jumps = self.jumps.write().unwrap();
+        let mut pos = self.pos.write().unwrap();
+
+        jumps.truncate(*pos);
+
+        if jumps.last().is_some_and(|last| last.dir == from.dir) {
+            jumps.pop();
+        }
+
+        jumps.push(from);
+
+        if jumps.len() > JUMPLIST_LIMIT {
+            jumps.remove(0);
+        }
+
+        *pos = jumps.len();
+    }
+
+    pub fn back(&self, current: Jump) -> Option<Jump> {
+        let mut jumps = self.jumps.write().unwrap();
+        let mut pos = self.pos.write().unwrap();
+        let target = (0..*pos).rev().find(|&i| jumps[i].dir.is_dir())?;
+
+        if *pos == jumps.len() {
+            jumps.push(current);
+        } else {
+            jumps[*pos] = current;
+        }
+
+        *pos = target;
+
+        jumps.get(*pos).cloned()
+    }
+
+    pub fn forward(&self, current: Jump) -> Option<Jump> {
+        let mut jumps = self.jumps.write().unwrap();
+        let mut pos = self.pos.write().unwrap();
+        let target = (*pos + 1..jumps.len()).find(|&i| jumps[i].dir.is_dir())?;
+
+        jumps[*pos] = current;
+        *pos = target;
+
+        jumps.get(*pos).cloned()
+    }
+
+    pub fn history(&self) -> Vec<Jump> {
+        let jumps = self.jumps.read().unwrap();
+        let mut history: Vec<Jump> = vec![];
+
+        for jump in jumps.iter().rev() {
+            if !history.iter().any(|seen| seen.dir == jump.dir) {
+                history.push(jump.clone());
+            }
+        }
+
+        history
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+#[derive(Clone, Hash)]
+pub struct Mark {
+    pub dir: PathBuf,
+    pub focus: Option<PathBuf>,
+}
+
+pub struct Marks {
+    file: PathBuf,
+    marks: RwLock<BTreeMap<char, Mark>>,
+}
+
+impl Marks {
+    pub fn load(file: PathBuf) -> Self {
+        let marks = read_marks(&file);
+
+        Self {
+            file,
+            marks: RwLock::new(marks),
+        }
+    }
+
+    pub fn get(&self, c: char) -> Option<Mark> {
+        self.marks.read().unwrap().get(&c).cloned()
+    }
+
+    pub fn list(&self) -> Vec<(char, Mark)> {
+        self.marks
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(c, mark)| (*c, mark.clone()))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.marks.read().unwrap().len()
+    }
+
+    pub fn set(&self, c: char, mark: Mark) {
+        self.update(|marks| {
+            marks.insert(c, mark);
+        });
+    }
+
+    pub fn remove(&self, c: char) {
+        self.update(|marks| {
+            marks.remove(&c);
+        });
+    }
+
+    fn update<F: FnOnce(&mut BTreeMap<char, Mark>)>(&self, f: F) {
+        use std::fs;
+
+        let mut marks = self.marks.write().unwrap();
+
+        *marks = read_marks(&self.file);
+        f(&mut marks);
+
+        if let Err(e) = fs::write(&self.file, write_marks(&marks)) {
+            log::warn!("Couldn't save the marks\n\t{}", e.kind());
+        }
+    }
+}
+
+fn read_marks(file: &Path) -> BTreeMap<char, Mark> {
+    use crate::misc;
+    use std::fs;
+
+    let Ok(content) = fs::read_to_string(file) else {
+        return BTreeMap::new();
+    };
+
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split('\t');
+            let c = fields.next()?.chars().next()?;
+            let dir = misc::decode_path(fields.next()?);
+            let focus = fields
+                .next()
+                .filter(|focus| !focus.is_empty())
+                .map(misc::decode_path);
+
+            Some((c, Mark { dir, focus }))
+        })
+        .collect()
+}
+
+fn write_marks(marks: &BTreeMap<char, Mark>) -> String {
+    use crate::misc;
+
+    marks
+        .iter()
+        .map(|(c, mark)| {
+            format!(
+                "{c}\t{}\t{}\n",
+                misc::encode_path(&mark.dir),
+                mark.focus
+                    .as_deref()
+                    .map(misc::encode_path)
+                    .unwrap_or_default()
+            )
+        })
+        .collect()
+}
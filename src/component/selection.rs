@@ -1,4 +1,12 @@
-use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use super::Entry;
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{
+        RwLock,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
 
 pub struct Selection {
     is_enable: AtomicBool,
@@ -44,3 +52,97 @@ impl Selection {
         }
     }
 }
+
+pub struct PathSelection {
+    is_enable: AtomicBool,
+    anchor: RwLock<Option<PathBuf>>,
+    paths: RwLock<Vec<PathBuf>>,
+    indices: RwLock<Vec<usize>>,
+}
+
+impl PathSelection {
+    pub fn new() -> Self {
+        Self {
+            is_enable: AtomicBool::new(false),
+            anchor: RwLock::new(None),
+            paths: RwLock::new(vec![]),
+            indices: RwLock::new(vec![]),
+        }
+    }
+
+    pub fn is_enable(&self) -> bool {
+        self.is_enable.load(Ordering::Relaxed)
+    }
+
+    pub fn enable(&self, entries: &[Entry], pos: usize) {
+        *self.anchor.write().unwrap() = entries.get(pos).map(|entry| entry.path.clone());
+        self.is_enable.store(true, Ordering::Relaxed);
+        self.select(entries, pos);
+    }
+
+    pub fn disable(&self) {
+        self.is_enable.store(false, Ordering::Relaxed);
+        *self.anchor.write().unwrap() = None;
+        self.paths.write().unwrap().clear();
+        self.indices.write().unwrap().clear();
+    }
+
+    pub fn select(&self, entries: &[Entry], pos: usize) {
+        if !self.is_enable() || entries.is_empty() {
+            return;
+        }
+
+        let pos = pos.min(entries.len() - 1);
+        let anchor = self
+            .anchor
+            .read()
+            .unwrap()
+            .as_ref()
+            .and_then(|anchor| entries.iter().position(|entry| &entry.path == anchor))
+            .unwrap_or(pos);
+        let range = anchor.min(pos)..=anchor.max(pos);
+
+        *self.paths.write().unwrap() = entries[range.clone()]
+            .iter()
+            .map(|entry| entry.path.clone())
+            .collect();
+        *self.indices.write().unwrap() = range.collect();
+    }
+
+    pub fn restore(&self, entries: &[Entry], anchor: Option<PathBuf>, paths: Vec<PathBuf>) {
+        *self.anchor.write().unwrap() = anchor;
+        *self.paths.write().unwrap() = paths;
+        self.is_enable.store(true, Ordering::Relaxed);
+        self.resolve(entries);
+    }
+
+    pub fn resolve(&self, entries: &[Entry]) {
+        if !self.is_enable() {
+            return;
+        }
+
+        let mut paths = self.paths.write().unwrap();
+        let selected = paths.drain(..).collect::<HashSet<_>>();
+        let (indices, resolved): (Vec<_>, Vec<_>) = entries
+            .iter()
+            .enumerate()
+            .filter(|(_, entry)| selected.contains(&entry.path))
+            .map(|(i, entry)| (i, entry.path.clone()))
+            .unzip();
+
+        *paths = resolved;
+        *self.indices.write().unwrap() = indices;
+    }
+
+    pub fn collect(&self) -> Vec<usize> {
+        self.indices.read().unwrap().clone()
+    }
+
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.paths.read().unwrap().clone()
+    }
+
+    pub fn anchor(&self) -> Option<PathBuf> {
+        self.anchor.read().unwrap().clone()
+    }
+}
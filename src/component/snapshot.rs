@@ -0,0 +1,198 @@
+use regex::Regex;
+use std::{
+    fs::Metadata,
+    path::{Path, PathBuf},
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+    },
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum EntryKind {
+    File,
+    Dir,
+    SymlinkFile,
+    SymlinkDir,
+    Broken,
+    Other,
+}
+
+#[derive(Clone)]
+pub struct Entry {
+    pub path: PathBuf,
+    pub name: String,
+    pub kind: EntryKind,
+    pub metadata: Option<Metadata>,
+    pub link: Option<PathBuf>,
+}
+
+impl Entry {
+    pub fn new(path: PathBuf) -> Self {
+        use crate::misc;
+
+        let metadata = path.symlink_metadata().ok();
+        let kind = match metadata {
+            Some(ref metadata) if metadata.is_symlink() => match path.metadata() {
+                Ok(target) if target.is_dir() => EntryKind::SymlinkDir,
+                Ok(target) if target.is_file() => EntryKind::SymlinkFile,
+                Ok(_) => EntryKind::Other,
+                Err(_) => EntryKind::Broken,
+            },
+            Some(ref metadata) if metadata.is_dir() => EntryKind::Dir,
+            Some(ref metadata) if metadata.is_file() => EntryKind::File,
+            Some(_) => EntryKind::Other,
+            None => EntryKind::Broken,
+        };
+        let link = path.read_link().ok();
+
+        Self {
+            name: misc::entry_name(&path),
+            path,
+            kind,
+            metadata,
+            link,
+        }
+    }
+
+    pub fn is_dir(&self) -> bool {
+        matches!(self.kind, EntryKind::Dir | EntryKind::SymlinkDir)
+    }
+}
+
+pub fn read_entries(dir: &Path) -> Vec<Entry> {
+    use crate::config;
+
+    let Ok(read_dir) = dir.read_dir() else {
+        return vec![];
+    };
+
+    let mut entries = read_dir
+        .flatten()
+        .map(|entry| Entry::new(entry.path()))
+        .collect::<Vec<_>>();
+
+    (config::get().sort_func)(&mut entries);
+
+    entries
+}
+
+#[derive(PartialEq, Eq, Clone, Default)]
+pub struct EntryFilter {
+    pub show_hidden: bool,
+    pub pattern: String,
+}
+
+impl EntryFilter {
+    fn matcher(&self) -> Option<Regex> {
+        use crate::misc;
+
+        if self.pattern.is_empty() {
+            return None;
+        }
+
+        misc::name_regex(&self.pattern, false)
+    }
+
+    fn accepts(&self, entry: &Entry, matcher: Option<&Regex>) -> bool {
+        if entry.name == ".ep.ed" {
+            return true;
+        }
+
+        (self.show_hidden || !is_hidden(entry))
+            && matcher.is_none_or(|matcher| matcher.is_match(&entry.name))
+    }
+
+    pub fn apply(&self, entries: &[Entry]) -> Vec<Entry> {
+        let matcher = self.matcher();
+
+        entries
+            .iter()
+            .filter(|entry| self.accepts(entry, matcher.as_ref()))
+            .cloned()
+            .collect()
+    }
+}
+
+fn is_hidden(entry: &Entry) -> bool {
+    entry.name.starts_with('.') && entry.name != ".ep.ed"
+}
+
+struct Cache {
+    dir: PathBuf,
+    filter: EntryFilter,
+    all: Arc<Vec<Entry>>,
+    entries: Arc<Vec<Entry>>,
+}
+
+pub struct DirSnapshot {
+    cache: RwLock<Cache>,
+    is_stale: AtomicBool,
+    version: AtomicUsize,
+}
+
+impl DirSnapshot {
+    pub fn new() -> Self {
+        Self {
+            cache: RwLock::new(Cache {
+                dir: PathBuf::new(),
+                filter: EntryFilter::default(),
+                all: Arc::new(vec![]),
+                entries: Arc::new(vec![]),
+            }),
+            is_stale: AtomicBool::new(true),
+            version: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn load(&self, dir: &Path, filter: &EntryFilter) -> Arc<Vec<Entry>> {
+        {
+            let cache = self.cache.read().unwrap();
+
+            if !self.is_stale.load(Ordering::Relaxed) && cache.dir == dir && cache.filter == *filter
+            {
+                return cache.entries.clone();
+            }
+        }
+
+        let mut cache = self.cache.write().unwrap();
+        let is_reread = self.is_stale.swap(false, Ordering::Relaxed) || cache.dir != dir;
+
+        if is_reread {
+            log::info!("Read the '{}' entries", dir.to_string_lossy());
+
+            cache.dir = dir.to_path_buf();
+            cache.all = Arc::new(read_entries(dir));
+        }
+
+        if is_reread || cache.filter != *filter {
+            cache.filter = filter.clone();
+            cache.entries = Arc::new(filter.apply(&cache.all));
+            self.version.fetch_add(1, Ordering::Relaxed);
+        }
+
+        cache.entries.clone()
+    }
+
+    pub fn cached(&self) -> Arc<Vec<Entry>> {
+        self.cache.read().unwrap().entries.clone()
+    }
+
+    pub fn hidden_len(&self) -> usize {
+        let cache = self.cache.read().unwrap();
+
+        if cache.filter.show_hidden {
+            return 0;
+        }
+
+        cache.all.iter().filter(|entry| is_hidden(entry)).count()
+    }
+
+    pub fn invalidate(&self) {
+        self.is_stale.store(true, Ordering::Relaxed);
+    }
+
+    pub fn version(&self) -> usize {
+        self.version.load(Ordering::Relaxed)
+    }
+}
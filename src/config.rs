@@ -1,22 +1,28 @@
 mod delete;
 mod edit;
+mod grep;
 mod init;
 mod mapping;
 mod menu;
 mod paste;
+mod preview;
 mod sort;
 mod theme;
 
+use crate::component::Entry;
 use delete::DeleteConfig;
 use edit::{EditConfig, HijackMapping};
+use grep::GrepConfig;
 use mapping::{KeymapConfig, KeymapRegistry};
-use menu::{MenuConfig, MenuElement};
+use menu::MenuConfig;
 use paste::PasteConfig;
+use preview::PreviewConfig;
 use serde::{Deserialize, Serialize};
 use sort::SortConfig;
 use std::path::{Path, PathBuf};
 
 pub use init::setup_local;
+pub use menu::{MenuElement, save_menu_items};
 pub use theme::{Theme, download_official_theme, download_unofficial_theme};
 
 pub fn file_path() -> PathBuf {
@@ -34,12 +40,18 @@ pub fn file_path() -> PathBuf {
 struct ConfigModel {
     theme: String,
     native_cb: bool,
+    #[serde(default)]
+    hide_dotfiles: bool,
     sort: SortConfig,
     keymap: Option<KeymapConfig>,
     delete: DeleteConfig,
     paste: PasteConfig,
     edit: EditConfig,
     menu: MenuConfig,
+    #[serde(default)]
+    preview: PreviewConfig,
+    #[serde(default)]
+    grep: GrepConfig,
 }
 
 pub fn parse_check(s: &str) -> Result<(), toml::de::Error> {
@@ -103,12 +115,15 @@ impl Default for ConfigModel {
         Self {
             theme: "dark".to_string(),
             native_cb: false,
+            hide_dotfiles: false,
             sort: SortConfig::default(),
             keymap: None,
             delete: DeleteConfig::default(),
             paste: PasteConfig::default(),
             edit: EditConfig::default(),
             menu: MenuConfig::default(),
+            preview: PreviewConfig::default(),
+            grep: GrepConfig::default(),
         }
     }
 }
@@ -116,14 +131,18 @@ impl Default for ConfigModel {
 pub struct Config {
     pub theme: Theme,
     pub native_cb: bool,
-    pub sort_func: Box<dyn Fn(&mut [PathBuf]) + Send + Sync>,
+    pub hide_dotfiles: bool,
+    pub sort_func: Box<dyn Fn(&mut [Entry]) + Send + Sync>,
     pub keymaps: KeymapRegistry,
     pub hijack: HijackMapping,
     pub delete_to_temp: bool,
     pub delete_with_yank: bool,
     pub paste_similar_suffix: String,
     pub paste_is_overwrite: bool,
+    pub paste_preserve: bool,
     pub menu_elements: Vec<MenuElement>,
+    pub preview_width: u16,
+    pub grep_max_size: u64,
 }
 
 pub fn get() -> &'static Config {
@@ -162,6 +181,7 @@ pub fn get() -> &'static Config {
         log::info!("The {} theme successfully loaded", model.theme);
 
         let native_cb = model.native_cb;
+        let hide_dotfiles = model.hide_dotfiles;
 
         let sort_func = model.sort.sort_func();
 
@@ -180,6 +200,11 @@ pub fn get() -> &'static Config {
 
         let paste_similar_suffix = model.paste.copied_suffix;
         let paste_is_overwrite = model.paste.is_overwrite;
+        let paste_preserve = model.paste.preserve;
+
+        let preview_width = model.preview.width.clamp(10, 90);
+
+        let grep_max_size = model.grep.max_size * 1024 * 1024;
 
         log::info!("Keymaps successfully initialized");
 
@@ -188,6 +213,7 @@ pub fn get() -> &'static Config {
         Config {
             theme,
             native_cb,
+            hide_dotfiles,
             sort_func,
             keymaps,
             hijack,
@@ -195,7 +221,10 @@ pub fn get() -> &'static Config {
             delete_with_yank,
             paste_similar_suffix,
             paste_is_overwrite,
+            paste_preserve,
             menu_elements,
+            preview_width,
+            grep_max_size,
         }
     });
 
@@ -6,6 +6,18 @@ pub struct Exec {
     pub args: Vec<String>,
 }
 
+impl Exec {
+    pub fn line_arg(&self, line: usize) -> Option<String> {
+        let name = Path::new(&self.cmd).file_name()?.to_str()?;
+
+        matches!(
+            name,
+            "vi" | "vim" | "nvim" | "view" | "nano" | "emacs" | "micro" | "kak" | "ne" | "joe"
+        )
+        .then(|| format!("+{line}"))
+    }
+}
+
 impl<'de> Deserialize<'de> for Exec {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
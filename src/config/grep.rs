@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub(super) struct GrepConfig {
+    pub(super) max_size: u64,
+}
+
+impl Default for GrepConfig {
+    fn default() -> Self {
+        Self { max_size: 16 }
+    }
+}
@@ -60,10 +60,22 @@ macro_rules! imap {
     ($registry:expr, $keys:expr, $exec:expr $(,)?) => {{ $registry.register_raw(Mode::Input, Keymap::new($keys), $exec) }};
 }
 
+macro_rules! fmap {
+    ($registry:expr, $keys:expr, $exec:expr $(,)?) => {{ $registry.register_raw(Mode::Filter, Keymap::new($keys), $exec) }};
+}
+
 macro_rules! smap {
     ($registry:expr, $keys:expr, $exec:expr $(,)?) => {{ $registry.register_raw(Mode::Search, Keymap::new($keys), $exec) }};
 }
 
+macro_rules! lmap {
+    ($registry:expr, $keys:expr, $exec:expr $(,)?) => {{ $registry.register_raw(Mode::List, Keymap::new($keys), $exec) }};
+}
+
+macro_rules! pmap {
+    ($registry:expr, $keys:expr, $exec:expr $(,)?) => {{ $registry.register_raw(Mode::Peek, Keymap::new($keys), $exec) }};
+}
+
 macro_rules! mmap {
     ($registry:expr, $keys:expr, $exec:expr $(,)?) => {{ $registry.register_raw(Mode::Menu, Keymap::new($keys), $exec) }};
 }
@@ -114,6 +126,14 @@ pub(super) fn init_keymaps(
         if let Some(ref menu) = keyconf.menu {
             register_remap(registry, Mode::Menu, menu.collect_maps());
         }
+
+        if let Some(ref list) = keyconf.list {
+            register_remap(registry, Mode::List, list.collect_maps());
+        }
+
+        if let Some(ref peek) = keyconf.peek {
+            register_remap(registry, Mode::Peek, peek.collect_maps());
+        }
     }
 }
 
@@ -121,14 +141,14 @@ fn init_builtin_keymaps(r: &mut KeymapRegistry) {
     use crate::{
         proc::{
             Acommand, Command,
-            input::{self, search},
-            menu, view, yank,
+            input::{self, filter, search},
+            job, jumplist, list, mark, menu, pane, peek, tab, trash, undo, view, yank,
         },
         state::Mode,
         tui,
     };
 
-    nmap!(r, "<ESC>", Command(|s, _| view::refresh(s)));
+    nmap!(r, "<ESC>", Command(|s, _| view::reset(s)));
     nmap!(r, "ZZ", Command(|_, _| tui::close()));
     nmap!(r, "j", Command(|s, ctx| view::move_cursor(s, ctx, true)));
     nmap!(r, "k", Command(|s, ctx| view::move_cursor(s, ctx, false)));
@@ -142,12 +162,44 @@ fn init_builtin_keymaps(r: &mut KeymapRegistry) {
     nmap!(r, "a", Command(|s, _| input::ask_create(s)));
     nmap!(r, "dd", Command(|s, _| input::ask_delete(s)));
     nmap!(r, "yy", Command(|s, _| yank::yank(s)));
+    nmap!(r, "dx", Command(|s, _| yank::cut(s)));
     nmap!(r, "r", Command(|s, _| input::ask_rename(s)));
     nmap!(r, "p", Command(|s, _| input::ask_paste(s)));
     nmap!(r, "/", Command(|s, _| search::start_search(s)));
-    nmap!(r, "n", Command(|s, _| search::search_next(s)));
+    nmap!(r, "n", Command(|s, ctx| search::search_next(s, ctx, true)));
+    nmap!(r, "N", Command(|s, ctx| search::search_next(s, ctx, false)));
+    nmap!(r, "zf", Command(|s, _| filter::start_filter(s)));
     nmap!(r, "M", Command(|s, _| menu::toggle_menu_open(s)));
-    nmap!(r, "m", Command(|s, _| menu::toggle_menu(s)));
+    nmap!(r, "gm", Command(|s, _| menu::toggle_menu(s)));
+    nmap!(r, "P", Command(|s, _| menu::pin(s)));
+    nmap!(r, "g'", Command(|s, _| mark::open_list(s)));
+    nmap!(r, "<c-o>", Command(|s, _| jumplist::back(s)));
+    nmap!(r, "<c-i>", Command(|s, _| jumplist::forward(s)));
+    nmap!(r, "go", Command(|s, _| jumplist::forward(s)));
+    nmap!(r, "<TAB>", Command(|s, _| pane::switch_focus(s)));
+    nmap!(r, "gh", Command(|s, _| jumplist::open_history(s)));
+    nmap!(r, "<c-t>", Command(|s, _| tab::open(s)));
+    nmap!(r, "<c-w>", Command(|s, _| tab::close(s)));
+    nmap!(r, "gt", Command(tab::next));
+    nmap!(r, "gT", Command(tab::prev));
+    nmap!(r, "u", Acommand(|s, _| undo::undo(s)));
+    nmap!(r, "<c-r>", Acommand(|s, _| undo::redo(s)));
+    nmap!(r, "F", Command(|s, _| input::ask_find(s)));
+    nmap!(r, "gs", Command(|s, _| input::ask_grep(s)));
+    nmap!(r, "T", Command(|s, _| trash::open(s)));
+    nmap!(r, "J", Command(|s, _| job::open(s)));
+    nmap!(r, "zp", Command(|s, _| view::toggle_preview(s)));
+    nmap!(r, "zh", Command(|s, _| view::toggle_hidden(s)));
+    nmap!(r, "zd", Command(|s, _| pane::toggle_dual(s)));
+    nmap!(r, "zm", Command(|s, _| view::toggle_miller(s)));
+    nmap!(r, "C", Command(|s, _| pane::copy(s)));
+    nmap!(r, "X", Command(|s, _| pane::move_items(s)));
+    nmap!(r, "o", Command(|s, _| peek::open(s)));
+
+    for c in 'a'..='z' {
+        nmap!(r, &format!("m{c}"), Command(move |s, _| mark::set(s, c)));
+        nmap!(r, &format!("'{c}"), Command(move |s, _| mark::jump(s, c)));
+    }
 
     vmap!(r, "<ESC>", Command(|s, _| view::refresh(s)));
     vmap!(r, "ZZ", Command(|_, _| tui::close()));
@@ -163,12 +215,42 @@ fn init_builtin_keymaps(r: &mut KeymapRegistry) {
     vmap!(r, "a", Command(|s, _| input::ask_create(s)));
     vmap!(r, "d", Command(|s, _| input::ask_delete_selects(s)));
     vmap!(r, "y", Command(|s, _| yank::yank_selects(s)));
+    vmap!(r, "x", Command(|s, _| yank::cut_selects(s)));
     vmap!(r, "r", Command(|s, _| input::ask_rename(s)));
     vmap!(r, "p", Command(|s, _| input::ask_paste(s)));
     vmap!(r, "/", Command(|s, _| search::start_search(s)));
-    vmap!(r, "n", Command(|s, _| search::search_next(s)));
+    vmap!(r, "n", Command(|s, ctx| search::search_next(s, ctx, true)));
+    vmap!(r, "N", Command(|s, ctx| search::search_next(s, ctx, false)));
+    vmap!(r, "zf", Command(|s, _| filter::start_filter(s)));
     vmap!(r, "M", Command(|s, _| menu::toggle_menu_open(s)));
-    vmap!(r, "m", Command(|s, _| menu::toggle_menu(s)));
+    vmap!(r, "gm", Command(|s, _| menu::toggle_menu(s)));
+    vmap!(r, "P", Command(|s, _| menu::pin(s)));
+    vmap!(r, "g'", Command(|s, _| mark::open_list(s)));
+    vmap!(r, "<c-o>", Command(|s, _| jumplist::back(s)));
+    vmap!(r, "<c-i>", Command(|s, _| jumplist::forward(s)));
+    vmap!(r, "go", Command(|s, _| jumplist::forward(s)));
+    vmap!(r, "<TAB>", Command(|s, _| pane::switch_focus(s)));
+    vmap!(r, "gh", Command(|s, _| jumplist::open_history(s)));
+    vmap!(r, "<c-t>", Command(|s, _| tab::open(s)));
+    vmap!(r, "<c-w>", Command(|s, _| tab::close(s)));
+    vmap!(r, "gt", Command(tab::next));
+    vmap!(r, "gT", Command(tab::prev));
+    vmap!(r, "F", Command(|s, _| input::ask_find(s)));
+    vmap!(r, "gs", Command(|s, _| input::ask_grep(s)));
+    vmap!(r, "T", Command(|s, _| trash::open(s)));
+    vmap!(r, "J", Command(|s, _| job::open(s)));
+    vmap!(r, "zp", Command(|s, _| view::toggle_preview(s)));
+    vmap!(r, "zh", Command(|s, _| view::toggle_hidden(s)));
+    vmap!(r, "zd", Command(|s, _| pane::toggle_dual(s)));
+    vmap!(r, "zm", Command(|s, _| view::toggle_miller(s)));
+    vmap!(r, "C", Command(|s, _| pane::copy(s)));
+    vmap!(r, "X", Command(|s, _| pane::move_items(s)));
+    vmap!(r, "o", Command(|s, _| peek::open(s)));
+
+    for c in 'a'..='z' {
+        vmap!(r, &format!("m{c}"), Command(move |s, _| mark::set(s, c)));
+        vmap!(r, &format!("'{c}"), Command(move |s, _| mark::jump(s, c)));
+    }
 
     imap!(r, "<ESC>", Command(|s, _| input::restore(s)));
     imap!(r, "<ENTER>", Acommand(|s, _| input::complete_input(s)));
@@ -193,6 +275,12 @@ fn init_builtin_keymaps(r: &mut KeymapRegistry) {
     imap!(r, "n", Command(|s, _| input::answer_or_put(s, 'n')));
     imap!(r, "Y", Command(|s, _| input::answer_or_put(s, 'Y')));
     imap!(r, "N", Command(|s, _| input::answer_or_put(s, 'N')));
+    imap!(r, "o", Command(|s, _| input::answer_or_put(s, 'o')));
+    imap!(r, "s", Command(|s, _| input::answer_or_put(s, 's')));
+    imap!(r, "r", Command(|s, _| input::answer_or_put(s, 'r')));
+    imap!(r, "O", Command(|s, _| input::answer_or_put(s, 'O')));
+    imap!(r, "S", Command(|s, _| input::answer_or_put(s, 'S')));
+    imap!(r, "R", Command(|s, _| input::answer_or_put(s, 'R')));
 
     smap!(r, "<ESC>", Command(|s, _| input::restore(s)));
     smap!(r, "<ENTER>", Command(|s, _| input::complete_input(s)));
@@ -201,6 +289,8 @@ fn init_builtin_keymaps(r: &mut KeymapRegistry) {
 
     smap!(r, "<c-h>", Command(|s, _| s.input.input.shift_back()));
     smap!(r, "<c-l>", Command(|s, _| s.input.input.shift()));
+    smap!(r, "<c-p>", Command(|s, _| search::history_prev(s)));
+    smap!(r, "<c-n>", Command(|s, _| search::history_next(s)));
 
     smap!(r, "<SPACE>", Command(|s, _| search::put(s, ' ')));
     smap!(r, "<LT>", Command(|s, _| search::put(s, '<')));
@@ -213,14 +303,62 @@ fn init_builtin_keymaps(r: &mut KeymapRegistry) {
         );
     }
 
+    fmap!(r, "<ESC>", Command(|s, _| input::restore(s)));
+    fmap!(r, "<ENTER>", Command(|s, _| input::complete_input(s)));
+    fmap!(r, "<BS>", Command(|s, _| filter::pop(s)));
+    fmap!(r, "<DEL>", Command(|s, _| filter::pop_front(s)));
+
+    fmap!(r, "<c-h>", Command(|s, _| s.input.input.shift_back()));
+    fmap!(r, "<c-l>", Command(|s, _| s.input.input.shift()));
+
+    fmap!(r, "<SPACE>", Command(|s, _| filter::put(s, ' ')));
+    fmap!(r, "<LT>", Command(|s, _| filter::put(s, '<')));
+
+    for i_key in ('!'..='~').filter(|c| *c != '<') {
+        fmap!(
+            r,
+            &i_key.to_string(),
+            Command(move |s, _| filter::put(s, i_key))
+        );
+    }
+
+    lmap!(r, "<ESC>", Command(|s, _| list::close(s)));
+    lmap!(r, "ZZ", Command(|_, _| tui::close()));
+    lmap!(r, "j", Command(|s, ctx| list::move_cursor(s, ctx, true)));
+    lmap!(r, "k", Command(|s, ctx| list::move_cursor(s, ctx, false)));
+    lmap!(r, "G", Command(|s, _| list::move_cursor_too(s, true)));
+    lmap!(r, "gg", Command(|s, _| list::move_cursor_too(s, false)));
+    lmap!(r, "gj", Command(|s, ctx| list::move_page(s, ctx, true)));
+    lmap!(r, "gk", Command(|s, ctx| list::move_page(s, ctx, false)));
+    lmap!(r, "V", Command(|s, _| list::toggle_vis(s)));
+    lmap!(r, "l", Command(|s, _| list::enter(s)));
+    lmap!(r, "dd", Command(|s, _| list::delete(s)));
+    lmap!(r, "D", Command(|s, _| list::clear(s)));
+
+    pmap!(r, "<ESC>", Command(|s, _| peek::close(s)));
+    pmap!(r, "q", Command(|s, _| peek::close(s)));
+    pmap!(r, "ZZ", Command(|_, _| tui::close()));
+    pmap!(r, "j", Command(|s, ctx| peek::scroll(s, ctx, true)));
+    pmap!(r, "k", Command(|s, ctx| peek::scroll(s, ctx, false)));
+    pmap!(r, "G", Command(|s, _| peek::scroll_too(s, true)));
+    pmap!(r, "gg", Command(|s, _| peek::scroll_too(s, false)));
+    pmap!(r, "gj", Command(|s, ctx| peek::scroll_page(s, ctx, true)));
+    pmap!(r, "gk", Command(|s, ctx| peek::scroll_page(s, ctx, false)));
+
     mmap!(r, "ZZ", Command(|_, _| tui::close()));
     mmap!(r, "M", Command(|s, _| menu::toggle_menu_open(s)));
     mmap!(r, "m", Command(|s, _| menu::toggle_menu(s)));
+    mmap!(r, "gm", Command(|s, _| menu::toggle_menu(s)));
     mmap!(r, "j", Command(|s, ctx| menu::move_cursor(s, ctx, true)));
     mmap!(r, "k", Command(|s, ctx| menu::move_cursor(s, ctx, false)));
     mmap!(r, "G", Command(|s, _| menu::move_cursor_too(s, true)));
     mmap!(r, "gg", Command(|s, _| menu::move_cursor_too(s, false)));
     mmap!(r, "l", Command(|s, _| menu::enter(s)));
+    mmap!(r, "P", Command(|s, _| menu::pin(s)));
+    mmap!(r, "dd", Command(|s, _| menu::remove(s)));
+    mmap!(r, "r", Command(|s, _| input::ask_rename_menu(s)));
+    mmap!(r, "J", Command(|s, _| menu::move_item(s, true)));
+    mmap!(r, "K", Command(|s, _| menu::move_item(s, false)));
 }
 
 fn override_builtin(model: &ConfigModel, r: &mut KeymapRegistry) {
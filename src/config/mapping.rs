@@ -11,6 +11,8 @@ pub(super) struct KeymapConfig {
     pub(super) normal: Option<UserDefinedMaps>,
     pub(super) visual: Option<UserDefinedMaps>,
     pub(super) menu: Option<UserDefinedMaps>,
+    pub(super) list: Option<UserDefinedMaps>,
+    pub(super) peek: Option<UserDefinedMaps>,
 }
 
 #[derive(Serialize)]
@@ -80,7 +82,7 @@ impl KeymapRegistry {
         let mut buf = vec![];
 
         for key in keys.into_iter() {
-            if mode == Mode::Input {
+            if matches!(mode, Mode::Input | Mode::Search | Mode::Filter) {
                 if maps.contains_key(&(mode, key.to_string())) {
                     res.push(Ok(vec![key]));
                 }
@@ -148,7 +150,7 @@ impl KeymapRegistry {
         parsed
             .into_iter()
             .map(|map| match map {
-                Ok(keys) if mode == Mode::Input => {
+                Ok(keys) if matches!(mode, Mode::Input | Mode::Search | Mode::Filter) => {
                     let cmd = self
                         .get(mode, Keymap::from(keys))
                         .expect("Incorrect code found");
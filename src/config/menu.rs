@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 use std::{
     fmt::Display,
+    io,
     path::{Path, PathBuf},
 };
 
@@ -25,13 +26,14 @@ impl Default for MenuConfig {
     }
 }
 
+#[derive(Clone, Hash)]
 pub struct MenuElement {
     pub tag: String,
     pub path: PathBuf,
 }
 
 impl MenuElement {
-    fn new<S: Display, P: AsRef<Path>>(tag: S, path: P) -> Self {
+    pub fn new<S: Display, P: AsRef<Path>>(tag: S, path: P) -> Self {
         Self {
             tag: tag.to_string(),
             path: path.as_ref().to_path_buf(),
@@ -68,3 +70,73 @@ impl<'de> Deserialize<'de> for MenuElement {
         }
     }
 }
+
+pub fn save_menu_items(items: &[MenuElement]) -> io::Result<()> {
+    use std::fs;
+    use toml_edit::{Array, DocumentMut, Item, Table, Value};
+
+    let path = super::file_path();
+    let mut doc = fs::read_to_string(&path)?
+        .parse::<DocumentMut>()
+        .map_err(io::Error::other)?;
+
+    let menu = doc
+        .entry("menu")
+        .or_insert_with(|| Item::Table(Table::new()))
+        .as_table_like_mut()
+        .ok_or_else(|| io::Error::other("'menu' is not a table"))?;
+
+    let prev = menu
+        .get("items")
+        .and_then(Item::as_array)
+        .cloned()
+        .unwrap_or_default();
+    let is_multiline = prev.is_empty()
+        || prev.iter().any(|item| {
+            item.decor()
+                .prefix()
+                .and_then(|prefix| prefix.as_str())
+                .is_some_and(|prefix| prefix.contains('\n'))
+        });
+
+    let mut array = Array::new();
+
+    for (i, element) in items.iter().enumerate() {
+        let mut value = Value::from(format!(
+            "{}:{}",
+            element.tag,
+            element.path.to_string_lossy()
+        ));
+
+        match prev.get(i) {
+            Some(prev) => *value.decor_mut() = prev.decor().clone(),
+            None if is_multiline => value.decor_mut().set_prefix("\n    "),
+            None if i != 0 => value.decor_mut().set_prefix(" "),
+            None => {}
+        }
+
+        array.push_formatted(value);
+    }
+
+    if prev.is_empty() {
+        array.set_trailing("\n");
+        array.set_trailing_comma(true);
+    } else {
+        array.set_trailing(prev.trailing().clone());
+        array.set_trailing_comma(prev.trailing_comma());
+    }
+
+    match menu.get_mut("items").and_then(Item::as_value_mut) {
+        Some(Value::Array(items)) => {
+            let decor = items.decor().clone();
+
+            *items = array;
+            *items.decor_mut() = decor;
+        }
+        _ => {
+            menu.insert("items", Item::Value(Value::Array(array)));
+        }
+    }
+
+    fs::write(&path, doc.to_string())
+}
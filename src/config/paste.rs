@@ -5,6 +5,8 @@ pub(super) struct PasteConfig {
     pub(super) copied_suffix: String,
     pub(super) is_overwrite: bool,
     pub(super) listen_overwrite: bool,
+    #[serde(default)]
+    pub(super) preserve: bool,
 }
 
 impl Default for PasteConfig {
@@ -13,6 +15,7 @@ impl Default for PasteConfig {
             copied_suffix: String::from("_COPY"),
             is_overwrite: false,
             listen_overwrite: true,
+            preserve: false,
         }
     }
 }
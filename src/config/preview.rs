@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub(super) struct PreviewConfig {
+    pub(super) width: u16,
+}
+
+impl Default for PreviewConfig {
+    fn default() -> Self {
+        Self { width: 40 }
+    }
+}
@@ -1,5 +1,5 @@
+use crate::component::{Entry, EntryKind};
 use serde::{Deserialize, Serialize};
-use std::path::{Path, PathBuf};
 
 #[derive(Deserialize, Serialize, Default)]
 pub(super) struct SortConfig {
@@ -9,26 +9,22 @@ pub(super) struct SortConfig {
 }
 
 impl SortConfig {
-    pub(super) fn sort_func(&self) -> Box<dyn Fn(&mut [PathBuf]) + Send + Sync> {
-        use crate::misc;
-
+    pub(super) fn sort_func(&self) -> Box<dyn Fn(&mut [Entry]) + Send + Sync> {
         let ty = self.types;
         let group = self.groups;
         let is_reverse = self.reverse;
 
         Box::new(move |files| {
-            files.sort_by_key(|path| {
-                let entry_name = misc::entry_name(path);
+            files.sort_by(|a, b| {
+                let key = |entry: &Entry| {
+                    if entry.name == ".ep.ed" {
+                        return (255, 255);
+                    }
 
-                if &entry_name == ".ep.ed" {
-                    return (255, 255, entry_name.to_owned());
-                }
+                    (ty.parse_type(entry.kind), group.parse_group(&entry.name))
+                };
 
-                (
-                    ty.parse_type(path),
-                    group.parse_group(&entry_name),
-                    entry_name.to_owned(),
-                )
+                key(a).cmp(&key(b)).then_with(|| a.name.cmp(&b.name))
             });
 
             if is_reverse {
@@ -48,13 +44,13 @@ struct Types {
 }
 
 impl Types {
-    fn parse_type(&self, path: &Path) -> u8 {
-        match path {
-            path if path.is_symlink() && path.is_file() => self.symlink_file,
-            path if path.is_symlink() && path.is_dir() => self.symlink_dir,
-            path if path.is_dir() => self.directory,
-            path if path.is_file() => self.file,
-            _ => self.other,
+    fn parse_type(&self, kind: EntryKind) -> u8 {
+        match kind {
+            EntryKind::SymlinkFile => self.symlink_file,
+            EntryKind::SymlinkDir => self.symlink_dir,
+            EntryKind::Dir => self.directory,
+            EntryKind::File => self.file,
+            EntryKind::Broken | EntryKind::Other => self.other,
         }
     }
 }
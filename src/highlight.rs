@@ -0,0 +1,489 @@
+use std::path::Path;
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum Token {
+    Plain,
+    Keyword,
+    Type,
+    String,
+    Number,
+    Comment,
+}
+
+pub type Line = Vec<(Token, String)>;
+
+pub struct Syntax {
+    pub name: &'static str,
+    extensions: &'static [&'static str],
+    interpreters: &'static [&'static str],
+    keywords: &'static [&'static str],
+    line_comments: &'static [&'static str],
+    block_comment: Option<(&'static str, &'static str)>,
+    quotes: &'static [char],
+}
+
+const SYNTAXES: &[Syntax] = &[
+    Syntax {
+        name: "Rust",
+        extensions: &["rs"],
+        interpreters: &[],
+        keywords: &[
+            "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
+            "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod",
+            "move", "mut", "pub", "ref", "return", "self", "static", "struct", "super", "trait",
+            "true", "type", "unsafe", "use", "where", "while",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"'],
+    },
+    Syntax {
+        name: "C",
+        extensions: &["c", "h", "cc", "cpp", "cxx", "hpp", "hh"],
+        interpreters: &[],
+        keywords: &[
+            "auto",
+            "break",
+            "case",
+            "class",
+            "const",
+            "continue",
+            "default",
+            "delete",
+            "do",
+            "else",
+            "enum",
+            "extern",
+            "false",
+            "for",
+            "goto",
+            "if",
+            "inline",
+            "namespace",
+            "new",
+            "nullptr",
+            "private",
+            "protected",
+            "public",
+            "return",
+            "sizeof",
+            "static",
+            "struct",
+            "switch",
+            "template",
+            "this",
+            "true",
+            "typedef",
+            "union",
+            "using",
+            "virtual",
+            "volatile",
+            "while",
+            "#include",
+            "#define",
+            "#ifdef",
+            "#ifndef",
+            "#endif",
+            "#if",
+            "#else",
+            "#pragma",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "Go",
+        extensions: &["go"],
+        interpreters: &[],
+        keywords: &[
+            "break",
+            "case",
+            "chan",
+            "const",
+            "continue",
+            "default",
+            "defer",
+            "else",
+            "false",
+            "for",
+            "func",
+            "go",
+            "goto",
+            "if",
+            "import",
+            "interface",
+            "map",
+            "nil",
+            "package",
+            "range",
+            "return",
+            "select",
+            "struct",
+            "switch",
+            "true",
+            "type",
+            "var",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+    },
+    Syntax {
+        name: "Java",
+        extensions: &["java", "kt", "kts", "scala"],
+        interpreters: &[],
+        keywords: &[
+            "abstract",
+            "break",
+            "case",
+            "catch",
+            "class",
+            "continue",
+            "default",
+            "do",
+            "else",
+            "extends",
+            "false",
+            "final",
+            "finally",
+            "for",
+            "fun",
+            "if",
+            "implements",
+            "import",
+            "interface",
+            "new",
+            "null",
+            "object",
+            "override",
+            "package",
+            "private",
+            "protected",
+            "public",
+            "return",
+            "static",
+            "super",
+            "switch",
+            "this",
+            "throw",
+            "throws",
+            "true",
+            "try",
+            "val",
+            "var",
+            "void",
+            "when",
+            "while",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "JavaScript",
+        extensions: &["js", "mjs", "cjs", "jsx", "ts", "tsx"],
+        interpreters: &["node", "deno", "bun"],
+        keywords: &[
+            "async",
+            "await",
+            "break",
+            "case",
+            "catch",
+            "class",
+            "const",
+            "continue",
+            "default",
+            "delete",
+            "do",
+            "else",
+            "export",
+            "extends",
+            "false",
+            "finally",
+            "for",
+            "from",
+            "function",
+            "if",
+            "import",
+            "in",
+            "instanceof",
+            "interface",
+            "let",
+            "new",
+            "null",
+            "of",
+            "return",
+            "static",
+            "super",
+            "switch",
+            "this",
+            "throw",
+            "true",
+            "try",
+            "type",
+            "typeof",
+            "undefined",
+            "var",
+            "void",
+            "while",
+            "yield",
+        ],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"', '\'', '`'],
+    },
+    Syntax {
+        name: "Python",
+        extensions: &["py", "pyi"],
+        interpreters: &["python", "python2", "python3"],
+        keywords: &[
+            "and", "as", "assert", "async", "await", "break", "class", "continue", "def", "del",
+            "elif", "else", "except", "False", "finally", "for", "from", "global", "if", "import",
+            "in", "is", "lambda", "None", "nonlocal", "not", "or", "pass", "raise", "return",
+            "True", "try", "while", "with", "yield",
+        ],
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "Ruby",
+        extensions: &["rb"],
+        interpreters: &["ruby"],
+        keywords: &[
+            "begin", "break", "case", "class", "def", "do", "else", "elsif", "end", "ensure",
+            "false", "for", "if", "in", "module", "next", "nil", "redo", "rescue", "retry",
+            "return", "self", "super", "then", "true", "unless", "until", "when", "while", "yield",
+        ],
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "Lua",
+        extensions: &["lua"],
+        interpreters: &["lua", "luajit"],
+        keywords: &[
+            "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "goto",
+            "if", "in", "local", "nil", "not", "or", "repeat", "return", "then", "true", "until",
+            "while",
+        ],
+        line_comments: &["--"],
+        block_comment: Some(("--[[", "]]")),
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "Shell",
+        extensions: &["sh", "bash", "zsh", "fish"],
+        interpreters: &["sh", "bash", "zsh", "fish", "dash", "ksh"],
+        keywords: &[
+            "case", "do", "done", "elif", "else", "esac", "export", "fi", "for", "function", "if",
+            "in", "local", "readonly", "return", "select", "then", "until", "while",
+        ],
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "TOML",
+        extensions: &["toml", "ini", "conf", "cfg"],
+        interpreters: &[],
+        keywords: &["true", "false"],
+        line_comments: &["#", ";"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "YAML",
+        extensions: &["yml", "yaml"],
+        interpreters: &[],
+        keywords: &["true", "false", "null", "yes", "no"],
+        line_comments: &["#"],
+        block_comment: None,
+        quotes: &['"', '\''],
+    },
+    Syntax {
+        name: "JSON",
+        extensions: &["json", "jsonc"],
+        interpreters: &[],
+        keywords: &["true", "false", "null"],
+        line_comments: &["//"],
+        block_comment: Some(("/*", "*/")),
+        quotes: &['"'],
+    },
+];
+
+pub fn decode_text(buf: &[u8]) -> Option<String> {
+    if buf.contains(&0) {
+        return None;
+    }
+
+    let text = match std::str::from_utf8(buf) {
+        Ok(text) => text,
+        Err(e) if e.error_len().is_none() => std::str::from_utf8(&buf[..e.valid_up_to()]).ok()?,
+        Err(_) => return None,
+    };
+
+    Some(
+        text.replace('\t', "    ")
+            .chars()
+            .filter(|c| *c == '\n' || !c.is_control())
+            .collect(),
+    )
+}
+
+pub fn detect(path: &Path, text: &str) -> Option<&'static Syntax> {
+    let by_extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .and_then(|ext| {
+            SYNTAXES
+                .iter()
+                .find(|syntax| syntax.extensions.contains(&ext.as_str()))
+        });
+
+    by_extension.or_else(|| {
+        let shebang = text.lines().next()?.strip_prefix("#!")?;
+        let mut words = shebang.split_whitespace();
+        let program = words.next()?.rsplit('/').next()?;
+        let interpreter = if program == "env" {
+            words.find(|word| !word.starts_with('-'))?
+        } else {
+            program
+        };
+
+        SYNTAXES
+            .iter()
+            .find(|syntax| syntax.interpreters.contains(&interpreter))
+    })
+}
+
+pub fn highlight(syntax: Option<&Syntax>, text: &str) -> Vec<Line> {
+    let Some(syntax) = syntax else {
+        return text
+            .lines()
+            .map(|line| vec![(Token::Plain, line.to_string())])
+            .collect();
+    };
+
+    let mut in_block = false;
+
+    text.lines()
+        .map(|line| highlight_line(syntax, line, &mut in_block))
+        .collect()
+}
+
+fn highlight_line(syntax: &Syntax, line: &str, in_block: &mut bool) -> Line {
+    let mut tokens: Line = vec![];
+    let mut rest = line;
+
+    let mut push = |token: Token, s: &str| match tokens.last_mut() {
+        Some((last, buf)) if *last == token => buf.push_str(s),
+        _ => tokens.push((token, s.to_string())),
+    };
+
+    while !rest.is_empty() {
+        if *in_block {
+            let Some((_, end)) = syntax.block_comment else {
+                *in_block = false;
+                continue;
+            };
+
+            match rest.find(end) {
+                Some(pos) => {
+                    push(Token::Comment, &rest[..pos + end.len()]);
+                    rest = &rest[pos + end.len()..];
+                    *in_block = false;
+                }
+                None => {
+                    push(Token::Comment, rest);
+                    rest = "";
+                }
+            }
+
+            continue;
+        }
+
+        if let Some((start, _)) = syntax.block_comment
+            && rest.starts_with(start)
+        {
+            push(Token::Comment, start);
+            rest = &rest[start.len()..];
+            *in_block = true;
+
+            continue;
+        }
+
+        if syntax
+            .line_comments
+            .iter()
+            .any(|comment| rest.starts_with(comment))
+        {
+            push(Token::Comment, rest);
+            rest = "";
+
+            continue;
+        }
+
+        let Some(c) = rest.chars().next() else {
+            break;
+        };
+
+        let len = if syntax.quotes.contains(&c) {
+            let mut escaped = false;
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|&(_, next)| {
+                    let is_end = !escaped && next == c;
+
+                    escaped = !escaped && next == '\\';
+
+                    is_end
+                })
+                .map(|(i, next)| i + next.len_utf8())
+                .unwrap_or(rest.len());
+
+            push(Token::String, &rest[..end]);
+
+            end
+        } else if c.is_ascii_digit() {
+            let end = rest
+                .find(|next: char| !next.is_ascii_alphanumeric() && next != '.' && next != '_')
+                .unwrap_or(rest.len());
+
+            push(Token::Number, &rest[..end]);
+
+            end
+        } else if c.is_alphabetic() || c == '_' || c == '#' {
+            let end = rest
+                .char_indices()
+                .skip(1)
+                .find(|&(_, next)| !next.is_alphanumeric() && next != '_')
+                .map(|(i, _)| i)
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+
+            let token = if syntax.keywords.contains(&word) {
+                Token::Keyword
+            } else if word.starts_with(|c: char| c.is_uppercase()) {
+                Token::Type
+            } else {
+                Token::Plain
+            };
+
+            push(token, word);
+
+            end
+        } else {
+            push(Token::Plain, &rest[..c.len_utf8()]);
+
+            c.len_utf8()
+        };
+
+        rest = &rest[len..];
+    }
+
+    tokens
+}
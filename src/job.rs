@@ -0,0 +1,247 @@
+use std::{
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+    },
+    time::Instant,
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum JobStatus {
+    Running,
+    Done,
+    Failed,
+    Cancelled,
+}
+
+#[derive(Clone, Copy, Hash, Default)]
+pub struct Transfer {
+    pub bytes_done: u64,
+    pub bytes_total: u64,
+    pub files_done: usize,
+    pub files_total: usize,
+    pub rate: u64,
+    pub eta: Option<u64>,
+}
+
+impl Transfer {
+    fn merge(self, other: Transfer) -> Transfer {
+        let bytes_done = self.bytes_done + other.bytes_done;
+        let bytes_total = self.bytes_total + other.bytes_total;
+        let rate = self.rate + other.rate;
+
+        Transfer {
+            bytes_done,
+            bytes_total,
+            files_done: self.files_done + other.files_done,
+            files_total: self.files_total + other.files_total,
+            rate,
+            eta: (rate != 0).then(|| bytes_total.saturating_sub(bytes_done) / rate),
+        }
+    }
+}
+
+pub struct Job {
+    id: usize,
+    desc: String,
+    done: AtomicUsize,
+    total: AtomicUsize,
+    bytes_done: AtomicU64,
+    bytes_total: AtomicU64,
+    started_at: Instant,
+    status: RwLock<JobStatus>,
+    errors: RwLock<Vec<String>>,
+    is_cancelled: AtomicBool,
+}
+
+impl Job {
+    fn new(id: usize, desc: String) -> Self {
+        Self {
+            id,
+            desc,
+            done: AtomicUsize::new(0),
+            total: AtomicUsize::new(0),
+            bytes_done: AtomicU64::new(0),
+            bytes_total: AtomicU64::new(0),
+            started_at: Instant::now(),
+            status: RwLock::new(JobStatus::Running),
+            errors: RwLock::new(vec![]),
+            is_cancelled: AtomicBool::new(false),
+        }
+    }
+
+    pub fn id(&self) -> usize {
+        self.id
+    }
+
+    pub fn desc(&self) -> &str {
+        &self.desc
+    }
+
+    pub fn set_total(&self, total: usize) {
+        self.total.store(total, Ordering::Relaxed);
+    }
+
+    pub fn advance(&self, val: usize) {
+        self.done.fetch_add(val, Ordering::Relaxed);
+    }
+
+    pub fn progress(&self) -> (usize, usize) {
+        (
+            self.done.load(Ordering::Relaxed),
+            self.total.load(Ordering::Relaxed),
+        )
+    }
+
+    pub fn set_bytes_total(&self, total: u64) {
+        self.bytes_total.store(total, Ordering::Relaxed);
+    }
+
+    pub fn advance_bytes(&self, val: u64) {
+        self.bytes_done.fetch_add(val, Ordering::Relaxed);
+    }
+
+    pub fn transfer(&self) -> Option<Transfer> {
+        let bytes_total = self.bytes_total.load(Ordering::Relaxed);
+
+        if bytes_total == 0 {
+            return None;
+        }
+
+        let bytes_done = self.bytes_done.load(Ordering::Relaxed);
+        let (files_done, files_total) = self.progress();
+        let elapsed = self.started_at.elapsed().as_secs_f64();
+        let rate = if elapsed > 0.0 {
+            (bytes_done as f64 / elapsed) as u64
+        } else {
+            0
+        };
+
+        Some(Transfer {
+            bytes_done,
+            bytes_total,
+            files_done,
+            files_total,
+            rate,
+            eta: (rate != 0).then(|| bytes_total.saturating_sub(bytes_done) / rate),
+        })
+    }
+
+    pub fn error<S: ToString>(&self, msg: S) {
+        self.errors.write().unwrap().push(msg.to_string());
+    }
+
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.read().unwrap().clone()
+    }
+
+    pub fn status(&self) -> JobStatus {
+        *self.status.read().unwrap()
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.status() == JobStatus::Running
+    }
+
+    pub fn cancel(&self) {
+        if self.is_running() {
+            self.is_cancelled.store(true, Ordering::Relaxed);
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.is_cancelled.load(Ordering::Relaxed)
+    }
+
+    fn finish(&self) {
+        let status = if self.is_cancelled() {
+            JobStatus::Cancelled
+        } else if self.errors.read().unwrap().is_empty() {
+            JobStatus::Done
+        } else {
+            JobStatus::Failed
+        };
+
+        *self.status.write().unwrap() = status;
+    }
+}
+
+pub struct Jobs {
+    next_id: AtomicUsize,
+    jobs: RwLock<Vec<Arc<Job>>>,
+}
+
+impl Jobs {
+    pub fn new() -> Self {
+        Self {
+            next_id: AtomicUsize::new(1),
+            jobs: RwLock::new(vec![]),
+        }
+    }
+
+    pub fn spawn<S, F>(&self, desc: S, f: F) -> Arc<Job>
+    where
+        S: ToString,
+        F: FnOnce(&Job) + Send + 'static,
+    {
+        use std::panic::{self, AssertUnwindSafe};
+        use tokio::task;
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let job = Arc::new(Job::new(id, desc.to_string()));
+
+        self.jobs.write().unwrap().push(job.clone());
+
+        log::info!("Job #{id} started: {}", job.desc());
+
+        let running = job.clone();
+
+        task::spawn_blocking(move || {
+            if panic::catch_unwind(AssertUnwindSafe(|| f(&running))).is_err() {
+                running.error("The job panicked");
+            }
+
+            running.finish();
+
+            log::info!("Job #{} finished: {:?}", running.id(), running.status());
+        });
+
+        job
+    }
+
+    pub fn running(&self) -> usize {
+        self.jobs
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|job| job.is_running())
+            .count()
+    }
+
+    pub fn transfer(&self) -> Option<Transfer> {
+        self.jobs
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|job| job.is_running())
+            .filter_map(|job| job.transfer())
+            .reduce(Transfer::merge)
+    }
+
+    pub fn list(&self) -> Vec<Arc<Job>> {
+        self.jobs.read().unwrap().clone()
+    }
+
+    pub fn get(&self, id: usize) -> Option<Arc<Job>> {
+        self.jobs
+            .read()
+            .unwrap()
+            .iter()
+            .find(|job| job.id() == id)
+            .cloned()
+    }
+
+    pub fn clear_finished(&self) {
+        self.jobs.write().unwrap().retain(|job| job.is_running());
+    }
+}
@@ -6,10 +6,14 @@ mod clipboard;
 mod component;
 mod config;
 mod event;
+mod highlight;
+mod job;
 mod misc;
 mod proc;
 mod state;
+mod trash;
 mod tui;
+mod watcher;
 
 #[tokio::main]
 async fn main() {
@@ -62,6 +66,9 @@ async fn main() {
                                 state.work_dir.get().to_string_lossy()
                             ));
                         }
+                        proc::view::sync(&state);
+                        proc::job::sync(&state);
+                        proc::find::sync(&state);
                         canvas::draw(state);
                     })
                     .await;
@@ -1,4 +1,8 @@
-use std::path::{Path, PathBuf};
+use crate::job::Job;
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
 
 pub fn entry_name(path: &Path) -> String {
     if path == Path::new("/") {
@@ -14,35 +18,263 @@ pub fn entry_name(path: &Path) -> String {
     }
 }
 
-pub fn child_files_len(path: &Path) -> usize {
-    if !path.is_dir() || !path.exists() {
-        return 0;
+pub fn encode_path(path: &Path) -> String {
+    use std::os::unix::ffi::OsStrExt;
+
+    path.as_os_str()
+        .as_bytes()
+        .iter()
+        .map(|&b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => {
+                (b as char).to_string()
+            }
+            b => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+pub fn decode_path(s: &str) -> PathBuf {
+    use std::{ffi::OsStr, os::unix::ffi::OsStrExt};
+
+    let bytes = s.as_bytes();
+    let mut decoded = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%'
+            && let Some(hex) = s.get(i + 1..i + 3)
+            && let Ok(b) = u8::from_str_radix(hex, 16)
+        {
+            decoded.push(b);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+
+    PathBuf::from(OsStr::from_bytes(&decoded))
+}
+
+pub fn data_home() -> Option<PathBuf> {
+    use std::env;
+
+    match env::var_os("XDG_DATA_HOME") {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => env::var_os("HOME").map(|home| PathBuf::from(home).join(".local").join("share")),
     }
+}
+
+pub fn move_item(from: &Path, to: &Path) -> io::Result<()> {
+    use std::fs;
+
+    match fs::rename(from, to) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            log::info!(
+                "Cross-device move detected, copy the {} instead",
+                entry_name(from)
+            );
+
+            copy_recursive(from, to)?;
 
-    match path.read_dir() {
-        Ok(d) => d.count(),
-        Err(_) => 0,
+            if from.symlink_metadata()?.is_dir() {
+                fs::remove_dir_all(from)
+            } else {
+                fs::remove_file(from)
+            }
+        }
+        res => res,
     }
 }
 
-pub fn child_files(path: &Path) -> Vec<PathBuf> {
-    if !path.is_dir() || !path.exists() {
-        return vec![];
+fn copy_recursive(from: &Path, to: &Path) -> io::Result<()> {
+    use std::{fs, os::unix};
+    use walkdir::WalkDir;
+
+    let preserve = |from: &Path, to: &Path| {
+        if let Err(e) = preserve_attributes(from, to) {
+            log::warn!(
+                "Preserve the attributes of {} is failed\n\t{}",
+                to.to_string_lossy(),
+                e.kind()
+            );
+        }
+    };
+
+    let mut dirs = vec![];
+
+    for entry in WalkDir::new(from) {
+        let entry = entry?;
+        let rel_path = entry.path().strip_prefix(from).map_err(io::Error::other)?;
+        let to = to.join(rel_path);
+        let file_type = entry.file_type();
+
+        if file_type.is_symlink() {
+            unix::fs::symlink(entry.path().read_link()?, &to)?;
+            preserve(entry.path(), &to);
+        } else if file_type.is_dir() {
+            fs::create_dir(&to)?;
+            dirs.push((entry.path().to_path_buf(), to));
+        } else {
+            copy_file(entry.path(), &to, None)?;
+            preserve(entry.path(), &to);
+        }
     }
 
-    match path.read_dir() {
-        Ok(entries) => entries.flatten().map(|entry| entry.path()).collect(),
-        Err(_) => vec![],
+    for (from, to) in dirs.iter().rev() {
+        preserve(from, to);
     }
+
+    Ok(())
 }
 
-pub fn sorted_child_files(path: &Path) -> Vec<PathBuf> {
-    use crate::config;
+pub fn preserve_attributes(from: &Path, to: &Path) -> io::Result<()> {
+    use filetime::FileTime;
+    use std::{
+        fs,
+        os::unix::fs::{MetadataExt, lchown},
+    };
+
+    let metadata = from.symlink_metadata()?;
+
+    if let Err(e) = lchown(to, Some(metadata.uid()), Some(metadata.gid()))
+        && e.kind() != io::ErrorKind::PermissionDenied
+    {
+        return Err(e);
+    }
+
+    if !metadata.is_symlink() {
+        fs::set_permissions(to, metadata.permissions())?;
+    }
 
-    let mut child_files = child_files(path);
-    let config = config::get();
+    if let Ok(attrs) = xattr::list(from) {
+        for attr in attrs {
+            if let Ok(Some(value)) = xattr::get(from, &attr) {
+                xattr::set(to, &attr, &value).ok();
+            }
+        }
+    }
+
+    filetime::set_symlink_file_times(
+        to,
+        FileTime::from_last_access_time(&metadata),
+        FileTime::from_last_modification_time(&metadata),
+    )
+}
+
+pub fn copy_file(from: &Path, to: &Path, job: Option<&Job>) -> io::Result<u64> {
+    use std::{
+        fs::{self, File},
+        io::{Read, Write},
+    };
+
+    const CHUNK_SIZE: usize = 1024 * 1024;
+
+    let mut reader = File::open(from)?;
+    let mut writer = File::create(to)?;
+    let mut buf = vec![0u8; CHUNK_SIZE];
+    let mut copied = 0u64;
+
+    loop {
+        if job.is_some_and(|job| job.is_cancelled()) {
+            drop(writer);
+            fs::remove_file(to).ok();
+
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+
+        let len = match reader.read(&mut buf) {
+            Ok(0) => break,
+            Ok(len) => len,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+
+        writer.write_all(&buf[..len])?;
+        copied += len as u64;
+
+        if let Some(job) = job {
+            job.advance_bytes(len as u64);
+        }
+    }
+
+    writer.set_permissions(reader.metadata()?.permissions())?;
+
+    Ok(copied)
+}
+
+pub fn smart_regex(pattern: &str) -> Option<regex::Regex> {
+    use regex::RegexBuilder;
+
+    let mut is_escaped = false;
+    let has_upper = pattern.chars().any(|c| {
+        let is_upper = !is_escaped && c.is_uppercase();
+
+        is_escaped = !is_escaped && c == '\\';
+
+        is_upper
+    });
+
+    RegexBuilder::new(pattern)
+        .case_insensitive(!has_upper)
+        .build()
+        .ok()
+}
+
+pub fn name_regex(pattern: &str, is_fuzzy: bool) -> Option<regex::Regex> {
+    use regex::RegexBuilder;
+
+    let (source, is_regex) = match pattern.strip_prefix('/') {
+        Some(regex) => (regex.to_string(), true),
+        None if pattern.contains(['*', '?', '[']) => (glob_to_regex(pattern), false),
+        None if is_fuzzy => (
+            pattern
+                .chars()
+                .map(|c| regex::escape(&c.to_string()))
+                .collect::<Vec<_>>()
+                .join(".*"),
+            false,
+        ),
+        None => (regex::escape(pattern), false),
+    };
+
+    RegexBuilder::new(&source)
+        .case_insensitive(!is_regex && !pattern.chars().any(char::is_uppercase))
+        .build()
+        .ok()
+}
+
+fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("^");
+    let mut in_class = false;
+
+    for c in glob.chars() {
+        match c {
+            '*' if !in_class => regex.push_str(".*"),
+            '?' if !in_class => regex.push('.'),
+            '[' if !in_class => {
+                in_class = true;
+                regex.push('[');
+            }
+            ']' if in_class => {
+                in_class = false;
+                regex.push(']');
+            }
+            '!' if in_class && regex.ends_with('[') => regex.push('^'),
+            '\\' | '^' | '[' if in_class => {
+                regex.push('\\');
+                regex.push(c);
+            }
+            c if in_class => regex.push(c),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+
+    if in_class {
+        regex.push(']');
+    }
 
-    (config.sort_func)(&mut child_files);
+    regex.push('$');
 
-    child_files
+    regex
 }
@@ -1,5 +1,16 @@
+pub mod find;
+pub mod grep;
 pub mod input;
+pub mod job;
+pub mod jumplist;
+pub mod list;
+pub mod mark;
 pub mod menu;
+pub mod pane;
+pub mod peek;
+pub mod tab;
+pub mod trash;
+pub mod undo;
 pub mod view;
 pub mod yank;
 
@@ -33,13 +44,6 @@ impl<F: Fn(Arc<State>, CommandContext) + Send + Sync> Runnable for Acommand<F> {
     fn run(&'static self, state: Arc<State>, ctx: CommandContext) {
         use tokio::task;
 
-        task::spawn_blocking(move || {
-            state.proc_counter.increment();
-
-            (self.0)(state.clone(), ctx);
-            std::thread::sleep(std::time::Duration::from_secs(3));
-
-            state.proc_counter.decrement();
-        });
+        task::spawn_blocking(move || (self.0)(state, ctx));
     }
 }
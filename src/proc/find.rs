@@ -0,0 +1,130 @@
+use crate::{
+    job::Job,
+    state::{ListItem, ListKind, State},
+};
+use regex::Regex;
+use std::{path::Path, sync::Arc};
+
+pub fn start(state: Arc<State>, pattern: &str) {
+    use super::list;
+    use crate::misc;
+
+    let Some(regex) = misc::name_regex(pattern, true) else {
+        crate::log!("Invalid pattern: {pattern}");
+
+        return;
+    };
+
+    let root = state.work_dir.get();
+    let generation = state.finder.reset();
+    let pattern = pattern.to_string();
+    let st = state.clone();
+
+    log::info!("Find the '{pattern}' in the '{}'", root.to_string_lossy());
+
+    let job = state.jobs.spawn(format!("Find '{pattern}'"), move |job| {
+        walk(&st, job, &root, &regex, generation);
+
+        if !job.is_cancelled() {
+            crate::log!("Found {} items by '{pattern}'", st.finder.len());
+        }
+    });
+
+    state.finder.attach(job);
+    list::open(&state, ListKind::Find, state.finder.results());
+}
+
+fn walk(state: &State, job: &Job, root: &Path, regex: &Regex, generation: usize) {
+    use walkdir::WalkDir;
+
+    let is_hidden_shown = state.flag.is_hidden_shown.get();
+    let entries = WalkDir::new(root)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| {
+            is_hidden_shown || !entry.file_name().to_string_lossy().starts_with('.')
+        });
+
+    for entry in entries {
+        if job.is_cancelled() {
+            break;
+        }
+
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                log::warn!("Find skipped an entry\n\t{e}");
+
+                continue;
+            }
+        };
+
+        job.advance(1);
+
+        if !regex.is_match(&entry.file_name().to_string_lossy()) {
+            continue;
+        }
+
+        let file_type = entry.file_type();
+        let detail = if file_type.is_symlink() {
+            "l"
+        } else if file_type.is_dir() {
+            "d"
+        } else {
+            "-"
+        };
+
+        state.finder.push(
+            generation,
+            ListItem {
+                label: entry
+                    .path()
+                    .strip_prefix(root)
+                    .unwrap_or(entry.path())
+                    .to_string_lossy()
+                    .to_string(),
+                detail: detail.to_string(),
+                path: entry.into_path(),
+                ..Default::default()
+            },
+        );
+    }
+}
+
+pub fn sync(state: &State) {
+    let Some(kind @ (ListKind::Find | ListKind::Grep)) = state.list.kind() else {
+        return;
+    };
+
+    if state.finder.is_changed() {
+        state.list.open(kind, state.finder.results());
+    }
+}
+
+pub fn jump(state: Arc<State>) {
+    use super::view;
+
+    let Some(target) = state.list.items().get(state.list.cursor.current()).cloned() else {
+        return;
+    };
+
+    let Some(parent) = target.path.parent() else {
+        return;
+    };
+
+    log::info!("Jump to the '{}'", target.path.to_string_lossy());
+
+    state.finder.cancel();
+    state.list.close();
+
+    view::move_dir(state.clone(), parent);
+
+    if let Some(pos) = state
+        .entries()
+        .iter()
+        .position(|entry| entry.path == target.path)
+    {
+        state.file_view.cursor.shift_p(pos);
+    }
+}
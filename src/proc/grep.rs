@@ -0,0 +1,169 @@
+use crate::{
+    job::Job,
+    state::{ListItem, ListKind, ListPayload, State},
+};
+use regex::Regex;
+use std::{
+    fs::File,
+    io::{self, BufReader},
+    path::Path,
+    sync::Arc,
+};
+
+const SNIPPET_LIMIT: usize = 200;
+const BINARY_CHECK_LEN: u64 = 8192;
+
+pub fn start(state: Arc<State>, pattern: &str) {
+    use super::list;
+    use crate::misc;
+
+    let Some(regex) = misc::smart_regex(pattern) else {
+        crate::log!("Invalid regex: {pattern}");
+
+        return;
+    };
+
+    let root = state.work_dir.get();
+    let generation = state.finder.reset();
+    let pattern = pattern.to_string();
+    let st = state.clone();
+
+    log::info!("Grep the '{pattern}' in the '{}'", root.to_string_lossy());
+
+    let job = state.jobs.spawn(format!("Grep '{pattern}'"), move |job| {
+        walk(&st, job, &root, &regex, generation);
+
+        if !job.is_cancelled() {
+            crate::log!("Found {} lines by '{pattern}'", st.finder.len());
+        }
+    });
+
+    state.finder.attach(job);
+    list::open(&state, ListKind::Grep, state.finder.results());
+}
+
+fn walk(state: &State, job: &Job, root: &Path, regex: &Regex, generation: usize) {
+    use crate::config;
+    use ignore::WalkBuilder;
+
+    let max_size = config::get().grep_max_size;
+    let files = WalkBuilder::new(root)
+        .hidden(!state.flag.is_hidden_shown.get())
+        .require_git(false)
+        .sort_by_file_name(|a, b| a.cmp(b))
+        .build();
+
+    for file in files {
+        if job.is_cancelled() {
+            break;
+        }
+
+        let file = match file {
+            Ok(file) => file,
+            Err(e) => {
+                log::warn!("Grep skipped an entry\n\t{e}");
+
+                continue;
+            }
+        };
+
+        if !file.file_type().is_some_and(|ty| ty.is_file()) {
+            continue;
+        }
+
+        job.advance(1);
+
+        let Some(lines) = read_lines(file.path(), max_size) else {
+            continue;
+        };
+
+        let rel = file
+            .path()
+            .strip_prefix(root)
+            .unwrap_or(file.path())
+            .to_string_lossy()
+            .to_string();
+
+        for (i, line) in lines.enumerate() {
+            if job.is_cancelled() {
+                break;
+            }
+
+            let Ok(line) = line else {
+                break;
+            };
+
+            let line = String::from_utf8_lossy(line.strip_suffix(b"\r").unwrap_or(&line));
+
+            if !regex.is_match(&line) {
+                continue;
+            }
+
+            state.finder.push(
+                generation,
+                ListItem {
+                    payload: ListPayload::Line(i + 1),
+                    path: file.path().to_path_buf(),
+                    label: snippet(&line),
+                    detail: format!("{rel}:{}:", i + 1),
+                },
+            );
+        }
+    }
+}
+
+fn read_lines(path: &Path, max_size: u64) -> Option<io::Split<BufReader<File>>> {
+    use std::io::{BufRead, Read, Seek};
+
+    let mut file = File::open(path).ok()?;
+
+    if file.metadata().ok()?.len() > max_size {
+        log::info!("Grep skipped the large '{}'", path.to_string_lossy());
+
+        return None;
+    }
+
+    let mut head = vec![];
+
+    file.by_ref()
+        .take(BINARY_CHECK_LEN)
+        .read_to_end(&mut head)
+        .ok()?;
+
+    if head.contains(&0) {
+        return None;
+    }
+
+    file.rewind().ok()?;
+
+    Some(BufReader::new(file).split(b'\n'))
+}
+
+fn snippet(line: &str) -> String {
+    line.trim()
+        .replace('\t', " ")
+        .chars()
+        .filter(|c| !c.is_control())
+        .take(SNIPPET_LIMIT)
+        .collect()
+}
+
+pub fn open(state: Arc<State>) {
+    use super::view;
+
+    let Some(target) = state.list.items().get(state.list.cursor.current()).cloned() else {
+        return;
+    };
+
+    let line = match target.payload {
+        ListPayload::Line(line) => Some(line),
+        _ => None,
+    };
+
+    log::info!(
+        "Open the '{}' at line {line:?}",
+        target.path.to_string_lossy()
+    );
+
+    view::open_file(&state, &target.path, line);
+}
@@ -1,6 +1,11 @@
 mod create;
 pub mod delete;
+pub mod filter;
+mod find;
+mod grep;
+mod menu;
 pub mod paste;
+mod purge;
 mod rename;
 pub mod search;
 
@@ -9,7 +14,11 @@ use std::sync::Arc;
 
 pub use create::ask_create;
 pub use delete::{ask_delete, ask_delete_selects};
-pub use paste::ask_paste;
+pub use find::ask_find;
+pub use grep::ask_grep;
+pub use menu::ask_rename_menu;
+pub use paste::{ask_paste, transfer};
+pub use purge::{ask_empty_trash, ask_purge};
 pub use rename::ask_rename;
 
 fn input_start(state: &State, tag: &str) {
@@ -41,6 +50,7 @@ fn input_start_with_select(state: &State, tag: &str) {
 
 pub fn complete_input(state: Arc<State>) {
     use super::view;
+    use crate::state::Mode;
 
     let input = &state.input;
 
@@ -49,20 +59,43 @@ pub fn complete_input(state: Arc<State>) {
     };
 
     let content = { input.input.take() };
+    let ctx = tag.split_once(":").map(|(_, ctx)| ctx).unwrap_or("");
 
     match tag.trim() {
         tag if tag.starts_with("CreateThisItem") => create::complete_create(&state, &content),
-        tag if tag.starts_with("DeleteThisItem") => delete::complete_delete(&state, &content),
+        tag if tag.starts_with("DeleteThisItem") => delete::complete_delete(&state, ctx, &content),
         tag if tag.starts_with("DeleteItems") => delete::complete_delete_selects(&state, &content),
-        tag if tag.starts_with("RenameThisItem") => rename::complete_rename(&state, &content),
-        tag if tag.starts_with("PasteFromCb") => paste::complete_paste(&state, &content),
+        tag if tag.starts_with("RenameThisItem") => rename::complete_rename(&state, ctx, &content),
+        tag if tag.starts_with("RenameMenuItem") => {
+            let Ok(index) = ctx.parse::<usize>() else {
+                panic!("Cannot parse the 'RenameMenuItem' context");
+            };
+
+            menu::complete_rename_menu(&state, index, &content)
+        }
+        tag if tag.starts_with("PasteConflict") => paste::complete_conflict(&state, &content),
         tag if tag.starts_with("Search") => search::complete_search(&state, &content),
+        tag if tag.starts_with("Filter") => filter::complete_filter(&state, &content),
+        tag if tag.starts_with("FindItems") => find::complete_find(state.clone(), &content),
+        tag if tag.starts_with("GrepItems") => grep::complete_grep(state.clone(), &content),
+        tag if tag.starts_with("PurgeTrash") => purge::complete_purge(&state, &content),
+        tag if tag.starts_with("EmptyTrash") => purge::complete_empty_trash(&state, &content),
 
         _ => panic!("Unknown input tag found: {tag}"),
     }
 
+    if state.input.tag().is_some_and(|next| next != tag) {
+        log::info!("Input continued: {tag}");
+
+        return;
+    }
+
     log::info!("Input end: {tag}");
     view::initialize(&state);
+
+    if tag.starts_with("RenameMenuItem") {
+        state.mode.switch(Mode::Menu);
+    }
 }
 
 pub fn restore(state: Arc<State>) {
@@ -90,8 +123,14 @@ pub fn restore(state: Arc<State>) {
             delete::restore_delete_selects(state, start_idx);
         }
         "RenameThisItem" => rename::restore_rename(state),
-        "PasteFromCb" => paste::restore_paste(state),
+        "RenameMenuItem" => menu::restore_rename_menu(state),
+        "PasteConflict" => paste::restore_conflict(state),
         "Search" => search::restore_search(state),
+        "Filter" => filter::restore_filter(state),
+        "FindItems" => find::restore_find(state),
+        "GrepItems" => grep::restore_grep(state),
+        "PurgeTrash" => purge::restore_purge(state),
+        "EmptyTrash" => purge::restore_empty_trash(state),
 
         _ => panic!("Unknown input tag found: {tag}"),
     }
@@ -100,7 +139,10 @@ pub fn restore(state: Arc<State>) {
 }
 
 fn is_logging_tag(tag: &str) -> bool {
-    matches!(tag, "DeleteThisItem" | "DeleteItems" | "PasteFromCb")
+    matches!(
+        tag,
+        "DeleteThisItem" | "DeleteItems" | "PasteConflict" | "PurgeTrash" | "EmptyTrash"
+    )
 }
 
 fn logging_input(state: &State) {
@@ -110,23 +152,30 @@ fn logging_input(state: &State) {
 
     let (tag, ctx) = tag.split_once(":").unwrap_or((tag.as_str(), ""));
 
-    if is_logging_tag(tag) {
-        let prefix = match tag {
-            "DeleteThisItem" => &format!("Delete the '{ctx}' (y/N): "),
-            "DeleteItems" => {
-                let Some((count, _)) = ctx.split_once(";") else {
-                    panic!("Cannot parse the 'DeleteItems' context");
-                };
+    let prefix = match tag {
+        "DeleteThisItem" => &format!("Delete the '{ctx}' (y/N): "),
+        "DeleteItems" => {
+            let Some((count, _)) = ctx.split_once(";") else {
+                panic!("Cannot parse the 'DeleteItems' context");
+            };
 
-                &format!("Delete {count} items (y/N): ")
-            }
-            "PasteFromCb" => "Overwrite a file (Y/n): ",
+            &format!("Delete {count} items (y/N): ")
+        }
+        "PasteConflict" => {
+            let (_, name) = ctx.split_once(";").unwrap_or(("", ctx));
 
-            _ => return,
-        };
+            &paste::conflict_prompt(name)
+        }
+        "PurgeTrash" => &format!("Purge {ctx} items permanently (y/N): "),
+        "EmptyTrash" => "Empty the trash (y/N): ",
+        "FindItems" => "Find: ",
+        "GrepItems" => "Grep: ",
+        "RenameMenuItem" => "Rename the sidemenu item: ",
 
-        crate::log!("{prefix}{}", state.input.input.buf_clone());
-    }
+        _ => return,
+    };
+
+    crate::log!("{prefix}{}", state.input.input.buf_clone());
 }
 
 pub fn put(state: Arc<State>, c: char) {
@@ -180,9 +229,22 @@ pub fn answer_or_put(state: Arc<State>, c: char) {
 
     let (tag, _) = tag.split_once(":").unwrap_or((tag.as_str(), ""));
 
-    if is_logging_tag(tag) {
-        log::info!("Answer y/n");
+    if !is_logging_tag(tag) {
+        return;
+    }
+
+    if is_answer(tag, c) {
+        log::info!("Answer {c}");
 
         complete_input(state);
+    } else if tag == "PasteConflict" {
+        pop(state);
+    }
+}
+
+fn is_answer(tag: &str, c: char) -> bool {
+    match tag {
+        "PasteConflict" => paste::is_answer(c),
+        _ => matches!(c, 'y' | 'Y' | 'n' | 'N'),
     }
 }
@@ -2,7 +2,7 @@ use crate::state::State;
 use std::{fs, io, path::Path, sync::Arc};
 
 pub fn ask_create(state: Arc<State>) {
-    use crate::misc;
+    use crate::proc::view;
     use std::fs;
 
     let wd = state.work_dir.get();
@@ -20,8 +20,8 @@ pub fn ask_create(state: Arc<State>) {
     let cursor = &state.file_view.cursor;
     let start_idx = cursor.current();
 
-    cursor.resize(misc::child_files_len(&wd));
-    cursor.shift_p(misc::child_files_len(&wd));
+    view::reload(&state);
+    cursor.shift_p(cursor.len());
 
     super::input_start(&state, &format!("CreateThisItem:{start_idx}"));
 
@@ -47,15 +47,28 @@ pub(super) fn restore_create(state: Arc<State>, start_idx: usize) {
 }
 
 pub(super) fn complete_create(state: &State, content: &str) {
-    use crate::misc;
+    use crate::{
+        component::{Operation, Stamp},
+        misc,
+        proc::view,
+    };
 
     let is_dir = content.ends_with("/");
     let path = state.work_dir.get().join(content);
+    let is_exists = path.exists();
 
     log::info!("Create the '{content}'");
 
     match create_item(&path, is_dir) {
         Ok(_) => {
+            if !is_exists {
+                state.journal.record(vec![Operation::Create {
+                    path: path.clone(),
+                    is_dir,
+                    stamp: Stamp::of(&path),
+                }]);
+            }
+
             log::info!("Remove a dummy file");
 
             if let Err(e) = fs::remove_file(state.work_dir.get().join(".ep.ed")) {
@@ -65,12 +78,13 @@ pub(super) fn complete_create(state: &State, content: &str) {
 
             let cursor = &state.file_view.cursor;
 
+            view::reload(state);
             cursor.reset();
 
-            let child_files = misc::sorted_child_files(&state.work_dir.get());
-            if let Some(pos) = child_files
+            if let Some(pos) = state
+                .entries()
                 .iter()
-                .position(|item| misc::entry_name(item) == content)
+                .position(|entry| entry.name == content)
             {
                 cursor.shift_p(pos);
                 log::info!("Cursor reset to {pos}");
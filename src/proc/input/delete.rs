@@ -1,13 +1,13 @@
-use crate::state::State;
-use std::{fs, io, path::Path, sync::Arc};
+use crate::{component::Operation, job::Job, state::State};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub fn ask_delete(state: Arc<State>) {
-    use crate::misc;
-
-    let child_files = misc::sorted_child_files(&state.work_dir.get());
-
-    if let Some(item) = child_files.get(state.file_view.cursor.current()) {
-        let target_name = misc::entry_name(item);
+    if let Some(item) = state.entries().get(state.file_view.cursor.current()) {
+        let target_name = &item.name;
 
         super::input_start(&state, &format!("DeleteThisItem:{target_name}"));
         crate::log!("Delete the '{target_name}' (y/N): ");
@@ -23,45 +23,62 @@ pub(super) fn restore_delete(state: Arc<State>) {
 pub fn delete_just(state: Arc<State>) {
     use crate::proc::view;
 
-    complete_delete(&state, "y");
+    if let Some(item) = state.entries().get(state.file_view.cursor.current()) {
+        start_delete(&state, item.path.clone());
+    }
 
     view::initialize(&state);
 }
 
-pub(super) fn complete_delete(state: &State, content: &str) {
-    use crate::misc;
-
+pub(super) fn complete_delete(state: &Arc<State>, target_name: &str, content: &str) {
     if !content.to_ascii_lowercase().starts_with("y") {
         log::info!("Delete cancelled");
 
         return;
     }
 
-    let child_files = misc::sorted_child_files(&state.work_dir.get());
-    let path = child_files.get(state.file_view.cursor.current());
+    let path = state.work_dir.get().join(target_name);
+
+    if path.symlink_metadata().is_err() {
+        log::warn!("The '{target_name}' was gone while asking");
+        crate::log!("'{target_name}' no longer exists");
+
+        return;
+    }
+
+    start_delete(state, path);
+}
+
+fn start_delete(state: &Arc<State>, path: PathBuf) {
+    use crate::{misc, proc::view};
+
+    let name = misc::entry_name(&path);
+    let st = state.clone();
+
+    state.jobs.spawn(format!("Delete {name}"), move |job| {
+        job.set_total(1);
 
-    if let Some(path) = path {
-        let name = misc::entry_name(path);
+        match delete_item(&path) {
+            Ok(op) => {
+                st.journal.record(op.into_iter().collect());
 
-        match delete_item(path) {
-            Ok(_) => {
                 log::info!("The '{name}' was successfully deleted");
                 crate::log!("'{name}' delete successful");
             }
             Err(e) => {
+                job.error(e.kind());
                 log::warn!("Delete a '{name}' is failed\n\t{}", e.kind());
-                crate::log!(
-                    "Failed to delete the '{}': {}",
-                    misc::entry_name(path),
-                    e.kind()
-                );
+                crate::log!("Failed to delete the '{name}': {}", e.kind());
             }
         }
-    }
+
+        job.advance(1);
+        view::reload(&st);
+    });
 }
 
-fn delete_item(path: &Path) -> io::Result<()> {
-    use crate::{config, misc, proc::yank};
+pub fn delete_item(path: &Path) -> io::Result<Option<Operation>> {
+    use crate::{config, misc, proc::yank, trash};
 
     log::info!("Delete the {}", misc::entry_name(path));
 
@@ -71,7 +88,7 @@ fn delete_item(path: &Path) -> io::Result<()> {
             misc::entry_name(path)
         );
 
-        return Ok(());
+        return Ok(None);
     }
 
     let Ok(metadata) = path.symlink_metadata() else {
@@ -80,54 +97,55 @@ fn delete_item(path: &Path) -> io::Result<()> {
             misc::entry_name(path)
         );
 
-        return Ok(());
+        return Ok(None);
     };
 
     let config = config::get();
 
     if config.delete_to_temp {
-        log::info!("Move the {} to temp", misc::entry_name(path));
+        log::info!("Move the {} to trash", misc::entry_name(path));
 
-        let trash = Path::new("/tmp/endolphine/Trash");
-        let trashed = trash.join(misc::entry_name(path));
+        let trashed = trash::put(path)?;
 
-        log::info!("Cleaning an item of same name in the trash");
-
-        if trashed.is_symlink() || trashed.is_file() {
-            fs::remove_file(&trashed)?;
-        } else if trashed.is_dir() {
-            fs::remove_dir_all(&trashed)?;
-        }
-
-        log::info!("That item successfully cleaned");
+        log::info!("The {} successfully trashed", misc::entry_name(path));
 
         if config.delete_with_yank {
             log::info!("Yank the trashed item");
 
-            yank::clip_files(&[&trashed])?;
+            yank::clip_files(&[&trashed], false)?;
 
             log::info!("The trashed item successfully yanked");
         }
 
-        fs::rename(path, trashed)
-    } else if metadata.is_symlink() || metadata.is_file() {
-        fs::remove_file(path)
+        Ok(Some(Operation::Delete {
+            path: path.to_path_buf(),
+            trashed: Some(trashed),
+        }))
     } else {
-        fs::remove_dir_all(path)
+        if metadata.is_symlink() || metadata.is_file() {
+            fs::remove_file(path)?;
+        } else {
+            fs::remove_dir_all(path)?;
+        }
+
+        Ok(Some(Operation::Delete {
+            path: path.to_path_buf(),
+            trashed: None,
+        }))
     }
 }
 
 pub fn ask_delete_selects(state: Arc<State>) {
-    let selection = state.file_view.selection.collect();
-    let start_idx = *selection
+    let selection = &state.file_view.selection;
+    let count = selection.paths().len();
+    let start_idx = selection
+        .collect()
         .first()
-        .unwrap_or(&state.file_view.cursor.current());
+        .copied()
+        .unwrap_or(state.file_view.cursor.current());
 
-    super::input_start_with_select(
-        &state,
-        &format!("DeleteItems:{};{start_idx}", selection.len()),
-    );
-    crate::log!("Delete {} items (y/N): ", selection.len());
+    super::input_start_with_select(&state, &format!("DeleteItems:{count};{start_idx}"));
+    crate::log!("Delete {count} items (y/N): ");
 }
 
 pub(super) fn restore_delete_selects(state: Arc<State>, start_idx: usize) {
@@ -146,46 +164,54 @@ pub(super) fn restore_delete_selects(state: Arc<State>, start_idx: usize) {
 pub fn delete_selects_just(state: Arc<State>) {
     use crate::proc::view;
 
-    complete_delete_selects(&state, "y");
+    start_delete_selects(&state, state.file_view.selection.paths());
 
     view::initialize(&state);
 }
 
-pub(super) fn complete_delete_selects(state: &State, content: &str) {
-    use crate::misc;
-
+pub(super) fn complete_delete_selects(state: &Arc<State>, content: &str) {
     if !content.to_ascii_lowercase().starts_with("y") {
         log::info!("Delete cancelled");
 
         return;
     }
 
-    let child_files = misc::sorted_child_files(&state.work_dir.get());
-    let paths = state
-        .file_view
-        .selection
-        .collect()
-        .into_iter()
-        .filter_map(|idx| child_files.get(idx))
-        .map(|path| path.as_path())
-        .collect::<Vec<_>>();
+    start_delete_selects(state, state.file_view.selection.paths());
+}
+
+fn start_delete_selects(state: &Arc<State>, paths: Vec<PathBuf>) {
+    use crate::proc::view;
 
     log::info!("Delete files: \n{paths:?}");
 
-    match delete_items(paths.clone()) {
-        Ok(_) => {
-            log::info!("Files was successfully deleted");
-            log::info!("{paths:?}");
-            crate::log!("{} items delete successful", paths.len());
-        }
-        Err(e) => {
-            log::warn!("Delete files is failed\n{}", e.kind());
-            crate::log!("Failed to delete items: {}", e.kind());
-        }
-    }
+    let st = state.clone();
+
+    state
+        .jobs
+        .spawn(format!("Delete {} items", paths.len()), move |job| {
+            let mut ops = vec![];
+            let res = delete_items(&paths, &mut ops, job);
+
+            st.journal.record(ops);
+
+            match res {
+                Ok(count) => {
+                    log::info!("Files was successfully deleted");
+                    log::info!("{paths:?}");
+                    crate::log!("{count} items delete successful");
+                }
+                Err(e) => {
+                    job.error(e.kind());
+                    log::warn!("Delete files is failed\n{}", e.kind());
+                    crate::log!("Failed to delete items: {}", e.kind());
+                }
+            }
+
+            view::reload(&st);
+        });
 }
 
-fn delete_items(paths: Vec<&Path>) -> io::Result<()> {
+fn delete_items(paths: &[PathBuf], ops: &mut Vec<Operation>, job: &Job) -> io::Result<usize> {
     use crate::{config, proc::yank};
 
     log::info!(
@@ -196,17 +222,42 @@ fn delete_items(paths: Vec<&Path>) -> io::Result<()> {
             .collect::<Vec<_>>()
     );
 
-    paths.iter().try_for_each(|path| delete_item(path))?;
+    job.set_total(paths.len());
+
+    let mut count = 0usize;
+
+    for path in paths.iter() {
+        if job.is_cancelled() {
+            log::info!("Delete cancelled");
+
+            break;
+        }
+
+        ops.extend(delete_item(path)?);
+        count += 1;
+        job.advance(1);
+    }
 
     let config = config::get();
 
     if config.delete_to_temp && config.delete_with_yank {
         log::info!("Yank items again");
 
-        yank::clip_files(&paths)?;
+        let trashed = ops
+            .iter()
+            .filter_map(|op| match op {
+                Operation::Delete {
+                    trashed: Some(trashed),
+                    ..
+                } => Some(trashed),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        yank::clip_files(&trashed, false)?;
 
         log::info!("The trashed items successfully yanked");
     }
 
-    Ok(())
+    Ok(count)
 }
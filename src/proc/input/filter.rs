@@ -0,0 +1,99 @@
+use crate::state::State;
+use std::sync::Arc;
+
+pub fn start_filter(state: Arc<State>) {
+    use crate::state::Mode;
+
+    let input = &state.input;
+
+    input.enable("Filter");
+    input.input.take();
+    input.input.insert(&state.filter.load());
+
+    state.file_view.selection.disable();
+    state.mode.switch(Mode::Filter);
+
+    log_buffer(&state);
+}
+
+fn log_buffer(state: &State) {
+    crate::log!("Filter: {}", state.filter.load());
+}
+
+fn apply(state: &State) {
+    use crate::proc::view;
+
+    view::reanchor(state, || state.filter.update(state.input.input.buf_clone()));
+
+    log_buffer(state);
+}
+
+pub fn put(state: Arc<State>, c: char) {
+    let previous = state.input.input.buf_clone();
+
+    state.input.input.put(c);
+
+    log::info!("Put a {c} to current filter");
+    log::info!(
+        "Previous: {previous}, Current: {}",
+        state.input.input.buf_clone()
+    );
+
+    apply(&state);
+}
+
+pub fn pop(state: Arc<State>) {
+    let previous = state.input.input.buf_clone();
+
+    state.input.input.pop();
+
+    log::info!("Pop from current filter");
+    log::info!(
+        "Previous: {previous}, Current: {}",
+        state.input.input.buf_clone()
+    );
+
+    apply(&state);
+}
+
+pub fn pop_front(state: Arc<State>) {
+    let previous = state.input.input.buf_clone();
+
+    state.input.input.pop_front();
+
+    log::info!("Pop front from current filter");
+    log::info!(
+        "Previous: {previous}, Current: {}",
+        state.input.input.buf_clone()
+    );
+
+    apply(&state);
+}
+
+pub(super) fn complete_filter(state: &State, content: &str) {
+    if content.is_empty() {
+        clear(state);
+    } else {
+        log::info!("Filter the listing by '{content}'");
+    }
+}
+
+pub(super) fn restore_filter(state: Arc<State>) {
+    use crate::proc::view;
+
+    clear(&state);
+    view::initialize(&state);
+}
+
+pub fn clear(state: &State) {
+    use crate::proc::view;
+
+    if state.filter.load().is_empty() {
+        return;
+    }
+
+    view::reanchor(state, || state.filter.clear());
+
+    log::info!("Filter cleared");
+    crate::log!("Filter cleared");
+}
@@ -0,0 +1,20 @@
+use crate::state::State;
+use std::sync::Arc;
+
+pub fn ask_find(state: Arc<State>) {
+    super::input_start(&state, "FindItems");
+
+    crate::log!("Find: ");
+}
+
+pub(super) fn complete_find(state: Arc<State>, content: &str) {
+    use crate::proc::find;
+
+    find::start(state, content);
+}
+
+pub(super) fn restore_find(state: Arc<State>) {
+    use crate::proc::view;
+
+    view::initialize(&state);
+}
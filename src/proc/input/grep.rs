@@ -0,0 +1,24 @@
+use crate::state::State;
+use std::sync::Arc;
+
+pub fn ask_grep(state: Arc<State>) {
+    super::input_start(&state, "GrepItems");
+
+    crate::log!("Grep: ");
+}
+
+pub(super) fn complete_grep(state: Arc<State>, content: &str) {
+    use crate::proc::grep;
+
+    if content.is_empty() {
+        return;
+    }
+
+    grep::start(state, content);
+}
+
+pub(super) fn restore_grep(state: Arc<State>) {
+    use crate::proc::view;
+
+    view::initialize(&state);
+}
@@ -0,0 +1,47 @@
+use crate::state::State;
+use std::sync::Arc;
+
+pub fn ask_rename_menu(state: Arc<State>) {
+    let index = state.sidemenu.cursor.current();
+    let Some(element) = state.sidemenu.elements().into_iter().nth(index) else {
+        return;
+    };
+
+    super::input_start(&state, &format!("RenameMenuItem:{index}"));
+
+    state.input.input.insert(&element.tag);
+    super::logging_input(&state);
+}
+
+pub(super) fn complete_rename_menu(state: &State, index: usize, content: &str) {
+    use crate::config;
+
+    if content.is_empty() || content.contains(':') {
+        crate::log!("The name of the sidemenu item cannot be empty or contain ':'");
+
+        return;
+    }
+
+    let elements = state.sidemenu.update(|elements| {
+        if let Some(element) = elements.get_mut(index) {
+            element.tag = content.to_string();
+        }
+    });
+
+    if let Err(e) = config::save_menu_items(&elements) {
+        log::warn!("Couldn't save the sidemenu\n\t{e}");
+        crate::log!("Failed to save the sidemenu: {e}");
+
+        return;
+    }
+
+    log::info!("Rename the sidemenu item {index} to '{content}'");
+    crate::log!("Renamed to '{content}'");
+}
+
+pub(super) fn restore_rename_menu(state: Arc<State>) {
+    use crate::{proc::view, state::Mode};
+
+    view::initialize(&state);
+    state.mode.switch(Mode::Menu);
+}
@@ -1,113 +1,358 @@
-use crate::state::State;
-use std::{io, path::Path, sync::Arc};
+use crate::{
+    component::{Operation, PastePlan, Resolution, Stamp},
+    job::Job,
+    state::State,
+};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub fn ask_paste(state: Arc<State>) {
-    super::input_start(&state, "PasteFromCb");
-    crate::log!("Overwrite a file (Y/n): ")
+    if let Some(plan) = prepare_plan(&state) {
+        ask_plan(&state, plan);
+    }
+}
+
+pub fn transfer(state: Arc<State>, dir: PathBuf, items: Vec<PathBuf>, is_cut: bool) {
+    log::info!(
+        "Transfer {} items to {}",
+        items.len(),
+        dir.to_string_lossy()
+    );
+
+    ask_plan(&state, new_plan(dir, items, is_cut, false));
+}
+
+fn ask_plan(state: &Arc<State>, plan: PastePlan) {
+    if plan.conflicts.is_empty() {
+        start_paste(state, plan);
+
+        return;
+    }
+
+    log::info!("{} conflicts found", plan.conflicts.len());
+
+    state.conflicts.start(plan);
+    ask_conflict(state);
+}
+
+fn ask_conflict(state: &State) {
+    use crate::misc;
+
+    let Some((idx, path)) = state.conflicts.current() else {
+        return;
+    };
+
+    let name = misc::entry_name(&path);
+
+    super::input_start(state, &format!("PasteConflict:{idx};{name}"));
+    crate::log!("{}", conflict_prompt(&name));
+}
+
+pub(super) fn conflict_prompt(name: &str) -> String {
+    format!("'{name}' already exists: (o)verwrite (s)kip (r)ename (n)ewer, upper case for all: ")
 }
 
-pub(super) fn restore_paste(state: Arc<State>) {
+pub(super) fn is_answer(c: char) -> bool {
+    Resolution::from_key(c).is_some()
+}
+
+pub(super) fn restore_conflict(state: Arc<State>) {
     use crate::proc::view;
 
+    state.conflicts.take();
+
+    log::info!("Paste cancelled");
+
     view::refresh(state.clone());
 }
 
+pub(super) fn complete_conflict(state: &Arc<State>, content: &str) {
+    let Some((resolution, apply_all)) = content.chars().next().and_then(Resolution::from_key)
+    else {
+        state.conflicts.take();
+
+        log::info!("Paste cancelled");
+
+        return;
+    };
+
+    log::info!("Resolve the conflict: {resolution:?}, apply to all: {apply_all}");
+
+    state.conflicts.resolve(resolution, apply_all);
+
+    if state.conflicts.current().is_some() {
+        ask_conflict(state);
+
+        return;
+    }
+
+    if let Some(plan) = state.conflicts.take() {
+        start_paste(state, plan);
+    }
+}
+
 pub fn paste_just(state: Arc<State>) {
     use crate::{config, proc::view};
 
-    complete_paste(
-        &state,
-        if config::get().paste_is_overwrite {
-            "y"
+    if let Some(mut plan) = prepare_plan(&state) {
+        plan.resolve_all(if config::get().paste_is_overwrite {
+            Resolution::Overwrite
         } else {
-            "n"
-        },
-    );
+            Resolution::Skip
+        });
+
+        start_paste(&state, plan);
+    }
 
     view::initialize(&state);
 }
 
-pub(super) fn complete_paste(state: &State, content: &str) {
-    use crate::{clipboard, config};
+fn prepare_plan(state: &State) -> Option<PastePlan> {
+    use crate::{clipboard, config, proc::yank};
 
-    let overwrite = content.to_ascii_lowercase().starts_with("y");
+    log::info!("Read the paths from clipboard");
 
-    log::info!("Paste from clipboard");
+    if config::get().native_cb && !clipboard::is_cmd_installed() {
+        crate::log!(
+            "Failed to paste from the clipboard: {} is not installed",
+            clipboard::command()
+        );
+        log::warn!(
+            "Paste from clipboard failed\n\t{} is not installed",
+            clipboard::command()
+        );
+
+        return None;
+    }
 
-    match paste_from_cb(&state.work_dir.get(), overwrite) {
-        Ok(count) => {
-            log::info!(
-                "Successfully paste from clipboard: \n{}",
-                if config::get().native_cb {
-                    clipboard::read_native("text/uri-list")
-                        .unwrap_or("Cannot read clipboard".to_string())
-                } else {
-                    clipboard::read().unwrap_or("Cannot read clipboard".to_string())
-                }
-            );
-            crate::log!("{count} items paste successful");
-        }
+    let (is_cut, items) = match yank::clipped_files() {
+        Ok(clipped) => clipped,
         Err(e) => {
             log::warn!("Paste from clipboard is failed\n\t{}", e.kind());
             crate::log!("Failed to paste from the clipboard: {}", e.kind());
+
+            return None;
         }
+    };
+
+    log::info!("Clipboard paths successfully readed");
+    log::info!("{items:?}");
+
+    Some(new_plan(state.work_dir.get(), items, is_cut, true))
+}
+
+fn new_plan(dir: PathBuf, items: Vec<PathBuf>, is_cut: bool, is_clipped: bool) -> PastePlan {
+    use crate::misc;
+
+    let conflicts = items
+        .iter()
+        .filter(|from| {
+            let to = dir.join(misc::entry_name(from));
+
+            from.as_path() != to && to.symlink_metadata().is_ok()
+        })
+        .map(|from| (from.clone(), None))
+        .collect();
+
+    PastePlan {
+        dir,
+        is_cut,
+        is_clipped,
+        items,
+        conflicts,
     }
 }
 
-fn paste_from_cb(dir: &Path, overwrite: bool) -> io::Result<usize> {
-    use crate::{clipboard, config, misc};
+fn start_paste(state: &Arc<State>, plan: PastePlan) {
+    log::info!("Paste from clipboard");
 
-    let config = config::get();
+    let st = state.clone();
 
-    log::info!("Read the paths from clipboard");
+    state.jobs.spawn(
+        format!("Paste into {}", plan.dir.to_string_lossy()),
+        move |job| {
+            use crate::proc::view;
 
-    let files = if config.native_cb {
-        if !clipboard::is_cmd_installed() {
-            crate::log!(
-                "Failed to paste from the clipboard: {} is not installed",
-                clipboard::command()
-            );
-            log::warn!(
-                "Paste from clipboard failed\n\t{} is not installed",
-                clipboard::command()
-            );
+            let mut ops = vec![];
+            let res = paste_plan(&plan, &mut ops, job);
 
-            return Ok(0);
-        }
+            st.journal.record(ops);
+            view::reload(&st);
+
+            match res {
+                Ok(count) => {
+                    log::info!("Successfully paste from clipboard");
+                    crate::log!(
+                        "{count} items {} successful",
+                        if plan.is_cut { "move" } else { "paste" }
+                    );
+                }
+                Err(e) => {
+                    job.error(e.kind());
+                    log::warn!("Paste from clipboard is failed\n\t{}", e.kind());
+                    crate::log!("Failed to paste from the clipboard: {}", e.kind());
+                }
+            }
+        },
+    );
+}
 
-        clipboard::read_native("text/uri-list")
+fn paste_plan(plan: &PastePlan, ops: &mut Vec<Operation>, job: &Job) -> io::Result<usize> {
+    use crate::{config, misc, proc::yank};
+
+    let is_cut = plan.is_cut;
+
+    if is_cut {
+        job.set_total(plan.items.len());
     } else {
-        clipboard::read()
-    }?;
+        let (entries, bytes) = measure(&plan.items);
 
-    log::info!("Clipboard paths successfully readed");
+        log::info!("{entries} entries, {bytes} bytes to copy");
 
-    let files = files
-        .lines()
-        .filter_map(|s| s.strip_prefix("file://"))
-        .map(Path::new)
-        .filter(|path| path.symlink_metadata().is_ok_and(|meta| meta.is_symlink()) || path.exists())
-        .collect::<Vec<_>>();
+        job.set_total(entries);
+        job.set_bytes_total(bytes);
+    }
 
-    let count = files
-        .iter()
-        .map(|from| {
-            let to = dir.join(misc::entry_name(from));
+    let mut count = 0usize;
+
+    for from in plan.items.iter() {
+        if job.is_cancelled() {
+            log::info!("Paste cancelled");
+
+            break;
+        }
+
+        let mut entry = misc::entry_name(from);
+        let resolution = plan.resolution_of(from);
+
+        match resolution {
+            Some(Resolution::Skip) => {
+                log::info!("Skip the {}", from.to_string_lossy());
+
+                if is_cut {
+                    job.advance(1);
+                } else {
+                    let (entries, bytes) = measure(std::slice::from_ref(from));
+
+                    job.advance(entries);
+                    job.advance_bytes(bytes);
+                }
+
+                continue;
+            }
+            Some(Resolution::Rename) => {
+                recursive_suffix(&plan.dir, &mut entry, &config::get().paste_similar_suffix);
+            }
+            _ => {}
+        }
+
+        let to = plan.dir.join(entry);
+        let policy = resolution.unwrap_or(Resolution::Skip);
+
+        let pasted = if is_cut {
+            log::info!(
+                "Move {} to {}",
+                from.to_string_lossy(),
+                to.to_string_lossy()
+            );
+
+            job.advance(1);
 
+            move_item(from, &to, policy, ops)
+        } else {
             log::info!(
                 "Copy {} to {}",
                 from.to_string_lossy(),
                 to.to_string_lossy()
             );
 
-            copy_item(*from, &to, overwrite)
-        })
-        .sum::<usize>();
+            copy_item(from, &to, policy, ops, Some(job))
+        };
+
+        count += pasted;
+    }
+
+    if is_cut && plan.is_clipped && count == plan.items.len() {
+        log::info!("Clear the clipboard after move");
+
+        yank::clear_clipped()?;
+    }
 
     Ok(count)
 }
 
-fn copy_item<P: AsRef<Path>>(from: P, to: P, overwrite: bool) -> usize {
+fn measure(paths: &[PathBuf]) -> (usize, u64) {
+    use walkdir::WalkDir;
+
+    paths
+        .iter()
+        .flat_map(|path| WalkDir::new(path).into_iter().flatten())
+        .fold((0, 0), |(entries, bytes), entry| {
+            let size = entry
+                .metadata()
+                .ok()
+                .filter(|meta| meta.is_file())
+                .map(|meta| meta.len())
+                .unwrap_or(0);
+
+            (entries + 1, bytes + size)
+        })
+}
+
+fn move_item(from: &Path, to: &Path, policy: Resolution, ops: &mut Vec<Operation>) -> usize {
+    use crate::misc;
+
+    if from == to {
+        return 0;
+    }
+
+    if from.starts_with(to) || to.starts_with(from) {
+        log::warn!(
+            "Move the {} into the {} is rejected",
+            from.to_string_lossy(),
+            to.to_string_lossy()
+        );
+
+        return 0;
+    }
+
+    if to.symlink_metadata().is_ok()
+        && (!should_overwrite(from, to, policy) || trash_target(to, ops).is_err())
+    {
+        return 0;
+    }
+
+    match misc::move_item(from, to) {
+        Ok(_) => {
+            ops.push(Operation::Rename {
+                from: from.to_path_buf(),
+                to: to.to_path_buf(),
+            });
+
+            1
+        }
+        Err(e) => {
+            log::warn!(
+                "Move the {} is failed\n\t{}",
+                from.to_string_lossy(),
+                e.kind()
+            );
+
+            0
+        }
+    }
+}
+
+pub fn copy_item<P: AsRef<Path>>(
+    from: P,
+    to: P,
+    policy: Resolution,
+    ops: &mut Vec<Operation>,
+    job: Option<&Job>,
+) -> usize {
     use crate::{config, misc};
     use std::{fs, os::unix};
     use walkdir::WalkDir;
@@ -129,20 +374,58 @@ fn copy_item<P: AsRef<Path>>(from: P, to: P, overwrite: bool) -> usize {
         to = parent.join(entry);
     }
 
+    let skip = |from: &Path| {
+        if let Some(job) = job {
+            job.advance(1);
+
+            if let Ok(meta) = from.symlink_metadata()
+                && meta.is_file()
+            {
+                job.advance_bytes(meta.len());
+            }
+        }
+    };
+
+    let preserve = |from: &Path, to: &Path| {
+        if config::get().paste_preserve
+            && let Err(e) = misc::preserve_attributes(from, to)
+        {
+            log::warn!(
+                "Preserve the attributes of {} is failed\n\t{}",
+                to.to_string_lossy(),
+                e.kind()
+            );
+        }
+    };
+
     let mut counter = 0usize;
+    let mut copied = vec![];
     let is_to_exists = to.symlink_metadata().is_ok_and(|meta| meta.is_symlink()) || to.exists();
+    let is_merge = is_to_exists && is_real_dir(from) && is_real_dir(&to);
+    let is_to_exists = is_to_exists
+        && (is_merge || !should_overwrite(from, &to, policy) || trash_target(&to, ops).is_err());
 
     match from {
         from if from.is_symlink() => {
             if let Ok(origin) = from.read_link()
-                && (!is_to_exists || overwrite)
+                && !is_to_exists
                 && unix::fs::symlink(origin, &to).is_ok()
             {
+                preserve(from, &to);
+                copied.push((from.to_path_buf(), to.clone()));
                 counter += 1;
             }
+
+            skip(from);
         }
         from if from.is_dir() => {
+            let mut dirs = vec![];
+
             for entry in WalkDir::new(from).into_iter().flatten() {
+                if job.is_some_and(|job| job.is_cancelled()) {
+                    break;
+                }
+
                 let Ok(rel_path) = entry.path().strip_prefix(from) else {
                     continue;
                 };
@@ -153,7 +436,15 @@ fn copy_item<P: AsRef<Path>>(from: P, to: P, overwrite: bool) -> usize {
                 let is_to_exists =
                     to.symlink_metadata().is_ok_and(|meta| meta.is_symlink()) || to.exists();
 
-                if is_to_exists && !overwrite {
+                if is_to_exists && is_real_dir(from) && is_real_dir(&to) {
+                    skip(from);
+                    continue;
+                }
+
+                if is_to_exists
+                    && (!should_overwrite(from, &to, policy) || trash_target(&to, ops).is_err())
+                {
+                    skip(from);
                     continue;
                 }
 
@@ -161,35 +452,138 @@ fn copy_item<P: AsRef<Path>>(from: P, to: P, overwrite: bool) -> usize {
                     && !parent.exists()
                     && let Err(_) = fs::create_dir_all(parent)
                 {
+                    skip(from);
                     continue;
                 }
 
-                if from.is_symlink() {
-                    if let Ok(from) = from.read_link()
-                        && unix::fs::symlink(from, &to).is_ok()
-                    {
-                        counter += 1;
+                let is_top = !dirs
+                    .iter()
+                    .any(|(_, dir): &(PathBuf, PathBuf)| to.starts_with(dir));
+
+                let is_copied = if from.is_symlink() {
+                    let is_copied = from
+                        .read_link()
+                        .and_then(|origin| unix::fs::symlink(origin, &to))
+                        .is_ok();
+
+                    if is_copied {
+                        preserve(from, &to);
                     }
+
+                    skip(from);
+
+                    is_copied
                 } else if from.is_dir() {
-                    if fs::create_dir_all(&to).is_ok() {
-                        counter += 1;
+                    let is_copied = fs::create_dir(&to).is_ok();
+
+                    if is_copied {
+                        dirs.push((from.to_path_buf(), to.clone()));
+                    }
+
+                    skip(from);
+
+                    is_copied
+                } else {
+                    let is_copied = misc::copy_file(from, &to, job).is_ok();
+
+                    if is_copied {
+                        preserve(from, &to);
                     }
-                } else if fs::copy(from, &to).is_ok() {
+
+                    if let Some(job) = job {
+                        job.advance(1);
+                    }
+
+                    is_copied
+                };
+
+                if is_copied {
                     counter += 1;
+
+                    if is_top {
+                        copied.push((from.to_path_buf(), to));
+                    }
                 }
             }
+
+            for (from, to) in dirs.iter().rev() {
+                preserve(from, to);
+            }
         }
         from => {
-            if (!is_to_exists || overwrite) && fs::copy(from, &to).is_ok() {
-                counter += 1;
+            if !is_to_exists {
+                if misc::copy_file(from, &to, job).is_ok() {
+                    preserve(from, &to);
+                    copied.push((from.to_path_buf(), to.clone()));
+                    counter += 1;
+                }
+
+                if let Some(job) = job {
+                    job.advance(1);
+                }
+            } else {
+                skip(from);
             }
         }
     }
 
+    for (from, to) in copied {
+        ops.push(Operation::Copy {
+            stamp: Stamp::of(&to),
+            from,
+            to,
+        });
+    }
+
     counter
 }
 
-fn recursive_suffix(dir: &Path, entry: &mut String, suffix: &str) {
+fn is_real_dir(path: &Path) -> bool {
+    path.symlink_metadata().is_ok_and(|meta| meta.is_dir())
+}
+
+fn trash_target(to: &Path, ops: &mut Vec<Operation>) -> io::Result<()> {
+    use crate::trash;
+
+    match trash::put(to) {
+        Ok(trashed) => {
+            log::info!("Trash the overwritten {}", to.to_string_lossy());
+
+            ops.push(Operation::Delete {
+                path: to.to_path_buf(),
+                trashed: Some(trashed),
+            });
+
+            Ok(())
+        }
+        Err(e) => {
+            log::warn!(
+                "Trash the overwritten {} is failed\n\t{}",
+                to.to_string_lossy(),
+                e.kind()
+            );
+
+            Err(e)
+        }
+    }
+}
+
+fn should_overwrite(from: &Path, to: &Path, policy: Resolution) -> bool {
+    match policy {
+        Resolution::Overwrite => true,
+        Resolution::OverwriteIfNewer => {
+            let modified = |path: &Path| path.symlink_metadata().and_then(|meta| meta.modified());
+
+            match (modified(from), modified(to)) {
+                (Ok(from), Ok(to)) => from > to,
+                _ => false,
+            }
+        }
+        Resolution::Skip | Resolution::Rename => false,
+    }
+}
+
+pub fn recursive_suffix(dir: &Path, entry: &mut String, suffix: &str) {
     let path = dir.join(&entry);
 
     if path.symlink_metadata().is_ok_and(|meta| meta.is_symlink()) || path.exists() {
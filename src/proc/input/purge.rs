@@ -0,0 +1,106 @@
+use crate::state::State;
+use std::sync::Arc;
+
+pub fn ask_purge(state: Arc<State>) {
+    let count = crate::proc::list::targets(&state).len();
+
+    if count == 0 {
+        return;
+    }
+
+    super::input_start_with_select(&state, &format!("PurgeTrash:{count}"));
+    crate::log!("Purge {count} items permanently (y/N): ");
+}
+
+pub(super) fn restore_purge(state: Arc<State>) {
+    use crate::proc::view;
+
+    view::initialize(&state);
+}
+
+pub(super) fn complete_purge(state: &Arc<State>, content: &str) {
+    use crate::{misc, proc::list, proc::trash as trash_view, trash};
+
+    if !content.to_ascii_lowercase().starts_with("y") {
+        log::info!("Purge cancelled");
+
+        return;
+    }
+
+    let targets = list::targets(state);
+    let mut count = 0usize;
+
+    for item in targets.iter() {
+        log::info!("Purge the {}", item.path.to_string_lossy());
+
+        match trash::purge(&item.path) {
+            Ok(_) => count += 1,
+            Err(e) => {
+                log::warn!(
+                    "Purge the {} is failed\n\t{}",
+                    item.path.to_string_lossy(),
+                    e.kind()
+                );
+                crate::log!(
+                    "Failed to purge the '{}': {}",
+                    misc::entry_name(&item.path),
+                    e.kind()
+                );
+            }
+        }
+    }
+
+    if count == targets.len() {
+        log::info!("{count} items was successfully purged");
+        crate::log!("{count} items purge successful");
+    }
+
+    trash_view::reload(state);
+}
+
+pub fn ask_empty_trash(state: Arc<State>) {
+    super::input_start_with_select(&state, "EmptyTrash");
+    crate::log!("Empty the trash (y/N): ");
+}
+
+pub(super) fn restore_empty_trash(state: Arc<State>) {
+    use crate::proc::view;
+
+    view::initialize(&state);
+}
+
+pub(super) fn complete_empty_trash(state: &Arc<State>, content: &str) {
+    use crate::{proc::trash as trash_view, trash};
+
+    if !content.to_ascii_lowercase().starts_with("y") {
+        log::info!("Empty the trash cancelled");
+
+        return;
+    }
+
+    let entries = trash::list();
+    let len = entries.len();
+    let failed = entries
+        .into_iter()
+        .filter(|entry| {
+            trash::purge(&entry.trashed)
+                .inspect_err(|e| {
+                    log::warn!(
+                        "Purge the {} is failed\n\t{}",
+                        entry.trashed.to_string_lossy(),
+                        e.kind()
+                    )
+                })
+                .is_err()
+        })
+        .count();
+
+    if failed == 0 {
+        log::info!("The trash was successfully emptied");
+        crate::log!("{len} items purge successful");
+    } else {
+        crate::log!("Failed to purge {failed} items");
+    }
+
+    trash_view::reload(state);
+}
@@ -2,18 +2,17 @@ use crate::state::State;
 use std::{fs, io, path::Path, sync::Arc};
 
 pub fn ask_rename(state: Arc<State>) {
-    use crate::misc;
-
-    let files = misc::sorted_child_files(&state.work_dir.get());
-    let pos = state.file_view.cursor.current();
-    let file = &files[pos];
+    let entries = state.entries();
+    let Some(file) = entries.get(state.file_view.cursor.current()) else {
+        return;
+    };
     let input = &state.input.input;
 
-    super::input_start(&state, &format!("RenameThisItem:{pos}"));
+    super::input_start(&state, &format!("RenameThisItem:{}", file.name));
 
-    input.insert(&misc::entry_name(file));
+    input.insert(&file.name);
 
-    if let Some(e) = file.extension().and_then(|e| e.to_str()) {
+    if let Some(e) = file.path.extension().and_then(|e| e.to_str()) {
         format!(".{e}").chars().for_each(|_| input.shift_back())
     }
 }
@@ -24,34 +23,46 @@ pub(super) fn restore_rename(state: Arc<State>) {
     view::initialize(&state);
 }
 
-pub(super) fn complete_rename(state: &State, content: &str) {
-    use crate::misc;
+pub(super) fn complete_rename(state: &State, target_name: &str, content: &str) {
+    use crate::{component::Operation, misc};
 
     let wd = state.work_dir.get();
-    let child_files = misc::sorted_child_files(&wd);
-
-    if let Some(target) = child_files.get(state.file_view.cursor.current()) {
-        let into = wd.join(content);
-
-        log::info!("Rename a file");
-
-        match rename_item(target, &into) {
-            Ok(_) => {
-                log::info!(
-                    "The '{}' was successfully renamed to '{}'",
-                    misc::entry_name(target),
-                    misc::entry_name(&into)
-                );
-                crate::log!(
-                    "'{}' renamed to '{}'",
-                    misc::entry_name(target),
-                    misc::entry_name(&into)
-                );
-            }
-            Err(e) => {
-                log::warn!("Rename a file is failed\n\t{}", e.kind());
-                crate::log!("Failed to rename item: {}", e.kind());
+    let target = wd.join(target_name);
+
+    if target.symlink_metadata().is_err() {
+        log::warn!("The '{target_name}' was gone while renaming");
+        crate::log!("'{target_name}' no longer exists");
+
+        return;
+    }
+
+    let into = wd.join(content);
+
+    log::info!("Rename a file");
+
+    match rename_item(&target, &into) {
+        Ok(_) => {
+            if into.exists() && !target.exists() {
+                state.journal.record(vec![Operation::Rename {
+                    from: target.clone(),
+                    to: into.clone(),
+                }]);
             }
+
+            log::info!(
+                "The '{}' was successfully renamed to '{}'",
+                misc::entry_name(&target),
+                misc::entry_name(&into)
+            );
+            crate::log!(
+                "'{}' renamed to '{}'",
+                misc::entry_name(&target),
+                misc::entry_name(&into)
+            );
+        }
+        Err(e) => {
+            log::warn!("Rename a file is failed\n\t{}", e.kind());
+            crate::log!("Failed to rename item: {}", e.kind());
         }
     }
 }
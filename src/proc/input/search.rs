@@ -1,4 +1,4 @@
-use crate::state::State;
+use crate::{proc::CommandContext, state::State};
 use std::sync::Arc;
 
 pub fn start_search(state: Arc<State>) {
@@ -10,6 +10,7 @@ pub fn start_search(state: Arc<State>) {
     input.input.take();
 
     state.grep.clear();
+    state.search.begin(state.file_view.cursor.current());
 
     state.file_view.selection.disable();
     state.mode.switch(Mode::Search);
@@ -21,11 +22,40 @@ fn log_buffer(state: &State) {
     crate::log!("/{}", state.grep.load());
 }
 
+fn move_to(state: &State, index: usize) {
+    use crate::proc::view;
+
+    let cursor = &state.file_view.cursor;
+
+    cursor.reset();
+    cursor.shift_p(index);
+
+    view::select_cursor_pos(state);
+}
+
+fn jump_incremental(state: &State) {
+    let grep = state.grep.load();
+    let origin = state.search.origin();
+    let matches = state
+        .search
+        .matches(&grep, &state.entries(), state.snapshot.version());
+
+    let target = matches
+        .iter()
+        .find(|&&i| i > origin)
+        .or(matches.first())
+        .copied()
+        .unwrap_or(origin);
+
+    move_to(state, target);
+
+    log::info!("Incremental search '{grep}' moved the cursor to {target}");
+}
+
 pub fn put(state: Arc<State>, c: char) {
     let previous = state.input.input.buf_clone();
 
     state.input.input.put(c);
-    state.grep.update(state.input.input.buf_clone());
 
     log::info!("Put a {c} to current search");
     log::info!(
@@ -33,6 +63,8 @@ pub fn put(state: Arc<State>, c: char) {
         state.input.input.buf_clone()
     );
 
+    state.grep.update(state.input.input.buf_clone());
+    jump_incremental(&state);
     log_buffer(&state);
 }
 
@@ -40,7 +72,6 @@ pub fn pop(state: Arc<State>) {
     let previous = state.input.input.buf_clone();
 
     state.input.input.pop();
-    state.grep.update(state.input.input.buf_clone());
 
     log::info!("Pop from current search");
     log::info!(
@@ -48,6 +79,8 @@ pub fn pop(state: Arc<State>) {
         state.input.input.buf_clone()
     );
 
+    state.grep.update(state.input.input.buf_clone());
+    jump_incremental(&state);
     log_buffer(&state);
 }
 
@@ -55,7 +88,6 @@ pub fn pop_front(state: Arc<State>) {
     let previous = state.input.input.buf_clone();
 
     state.input.input.pop_front();
-    state.grep.update(state.input.input.buf_clone());
 
     log::info!("Pop front from current input");
     log::info!(
@@ -63,54 +95,86 @@ pub fn pop_front(state: Arc<State>) {
         state.input.input.buf_clone()
     );
 
+    state.grep.update(state.input.input.buf_clone());
+    jump_incremental(&state);
     log_buffer(&state);
 }
 
-pub fn search_next(state: Arc<State>) {
-    complete_search(&state, &state.grep.load());
+pub fn history_prev(state: Arc<State>) {
+    let current = state.input.input.buf_clone();
 
-    log::info!("Move to next matches");
+    if let Some(pattern) = state.search.history_prev(&current) {
+        replace_buffer(&state, &pattern);
+    }
 }
 
-pub(super) fn complete_search(state: &State, content: &str) {
-    use crate::misc;
-    use regex::Regex;
-
-    if let Ok(reg) = Regex::new(content) {
-        log::info!("{content} is valid regex");
-
-        let child_files = misc::sorted_child_files(&state.work_dir.get());
-        let cursor = &state.file_view.cursor;
-        let cursor_pos = cursor.current();
-
-        let first_match = child_files[cursor_pos + 1..]
-            .iter()
-            .chain(child_files[..cursor_pos].iter())
-            .position(|f| reg.is_match(&misc::entry_name(f)))
-            .map(|pos| pos + 1)
-            .unwrap_or(0);
-
-        if first_match != 0 {
-            log::info!(
-                "The '{content}' was matched to '{}'",
-                child_files[cursor_pos + 1..]
-                    .iter()
-                    .chain(child_files[..cursor_pos].iter())
-                    .find(|f| reg.is_match(&misc::entry_name(f)))
-                    .map(|f| f.to_string_lossy().to_string())
-                    .unwrap_or("NaN".to_string())
-            );
-        }
-
-        cursor.shift_loop_p(first_match);
-        log_buffer(state);
-
-        log::info!("Cursor move down by {first_match}(loop)");
+pub fn history_next(state: Arc<State>) {
+    if let Some(pattern) = state.search.history_next() {
+        replace_buffer(&state, &pattern);
     }
 }
 
+fn replace_buffer(state: &State, pattern: &str) {
+    let input = &state.input.input;
+
+    input.take();
+    input.insert(pattern);
+
+    state.grep.update(pattern.to_string());
+    jump_incremental(state);
+    log_buffer(state);
+}
+
+pub fn search_next(state: Arc<State>, ctx: CommandContext, positive: bool) {
+    let grep = state.grep.load();
+    let matches = state
+        .search
+        .matches(&grep, &state.entries(), state.snapshot.version());
+
+    if matches.is_empty() {
+        crate::log!("Pattern not found: {grep}");
+
+        return;
+    }
+
+    let mut target = state.file_view.cursor.current();
+
+    for _ in 0..ctx.prenum.unwrap_or(1) {
+        target = if positive {
+            matches
+                .iter()
+                .find(|&&i| i > target)
+                .or(matches.first())
+                .copied()
+                .unwrap_or(target)
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&i| i < target)
+                .or(matches.last())
+                .copied()
+                .unwrap_or(target)
+        };
+    }
+
+    move_to(&state, target);
+    log_buffer(&state);
+
+    log::info!("Move to the match at {target}");
+}
+
+pub(super) fn complete_search(state: &State, content: &str) {
+    state.search.push_history(content);
+
+    log::info!("Search completed: {content}");
+}
+
 pub(super) fn restore_search(state: Arc<State>) {
     use crate::proc::view;
 
+    state.grep.clear();
+    move_to(&state, state.search.origin());
+
     view::initialize(&state);
 }
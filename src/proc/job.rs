@@ -0,0 +1,111 @@
+use crate::{
+    job::JobStatus,
+    state::{ListItem, ListKind, ListPayload, State},
+};
+use std::sync::Arc;
+
+fn collect_items(state: &State) -> Vec<ListItem> {
+    state
+        .jobs
+        .list()
+        .into_iter()
+        .rev()
+        .map(|job| {
+            let (done, total) = job.progress();
+            let status = match job.status() {
+                JobStatus::Running => format!("{done}/{total}"),
+                JobStatus::Done => String::from("done"),
+                JobStatus::Failed => format!("failed({})", job.errors().len()),
+                JobStatus::Cancelled => String::from("cancelled"),
+            };
+
+            ListItem {
+                payload: ListPayload::Job(job.id()),
+                label: job.desc().to_string(),
+                detail: format!("#{:<4} {:>12}", job.id(), status),
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+fn collect_errors(state: &State, id: usize) -> Vec<ListItem> {
+    state
+        .jobs
+        .get(id)
+        .map(|job| job.errors())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|err| ListItem {
+            label: err,
+            ..Default::default()
+        })
+        .collect()
+}
+
+pub fn open(state: Arc<State>) {
+    use super::list;
+
+    log::info!("Open the job list");
+
+    list::open(&state, ListKind::Jobs, collect_items(&state));
+}
+
+pub fn sync(state: &State) {
+    let (kind, items) = match state.list.kind() {
+        Some(ListKind::Jobs) => (ListKind::Jobs, collect_items(state)),
+        Some(ListKind::JobErrors(id)) => (ListKind::JobErrors(id), collect_errors(state, id)),
+        _ => return,
+    };
+
+    if *state.list.items() != items {
+        state.list.open(kind, items);
+    }
+}
+
+pub fn show_errors(state: Arc<State>) {
+    use super::list;
+
+    let Some(ListPayload::Job(id)) = state
+        .list
+        .items()
+        .get(state.list.cursor.current())
+        .map(|item| item.payload.clone())
+    else {
+        return;
+    };
+
+    log::info!("Open the errors of job #{id}");
+
+    list::open(&state, ListKind::JobErrors(id), collect_errors(&state, id));
+}
+
+pub fn cancel(state: Arc<State>) {
+    use super::list;
+
+    let targets = list::targets(&state);
+
+    for job in targets
+        .iter()
+        .filter_map(|item| match item.payload {
+            ListPayload::Job(id) => state.jobs.get(id),
+            _ => None,
+        })
+        .filter(|job| job.is_running())
+    {
+        log::info!("Cancel the job #{}", job.id());
+
+        job.cancel();
+        crate::log!("Cancel the '{}'", job.desc());
+    }
+
+    state.list.selection.disable();
+}
+
+pub fn clear(state: Arc<State>) {
+    log::info!("Clear the finished jobs");
+
+    state.jobs.clear_finished();
+    state.list.selection.disable();
+    sync(&state);
+}
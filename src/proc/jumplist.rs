@@ -0,0 +1,93 @@
+use crate::{
+    component::Jump,
+    state::{ListItem, ListKind, ListPayload, State},
+};
+use std::sync::Arc;
+
+fn open(state: Arc<State>, jump: Jump) {
+    use super::view;
+
+    view::change_dir(state.clone(), &jump.dir);
+
+    if let Some(focus) = jump.focus
+        && let Some(pos) = state.entries().iter().position(|entry| entry.path == focus)
+    {
+        state.file_view.cursor.shift_p(pos);
+    }
+}
+
+pub fn back(state: Arc<State>) {
+    use super::view;
+
+    let Some(jump) = state.jumplist.back(view::current_jump(&state)) else {
+        crate::log!("Already at the oldest jump");
+
+        return;
+    };
+
+    log::info!("Jump back to '{}'", jump.dir.to_string_lossy());
+
+    open(state, jump);
+}
+
+pub fn forward(state: Arc<State>) {
+    use super::view;
+
+    let Some(jump) = state.jumplist.forward(view::current_jump(&state)) else {
+        crate::log!("Already at the newest jump");
+
+        return;
+    };
+
+    log::info!("Jump forward to '{}'", jump.dir.to_string_lossy());
+
+    open(state, jump);
+}
+
+pub fn open_history(state: Arc<State>) {
+    use super::list;
+
+    log::info!("Open the directory history");
+
+    let items = state
+        .jumplist
+        .history()
+        .into_iter()
+        .enumerate()
+        .map(|(i, jump)| ListItem {
+            label: jump.dir.to_string_lossy().to_string(),
+            detail: format!("{i:>3}"),
+            path: jump.dir.clone(),
+            payload: ListPayload::Jump(jump),
+        })
+        .collect();
+
+    list::open(&state, ListKind::History, items);
+}
+
+pub fn enter(state: Arc<State>) {
+    use super::view;
+
+    let Some(ListPayload::Jump(jump)) = state
+        .list
+        .items()
+        .get(state.list.cursor.current())
+        .map(|item| item.payload.clone())
+    else {
+        return;
+    };
+
+    if !jump.dir.is_dir() {
+        crate::log!("The directory is not exists");
+
+        return;
+    }
+
+    state.list.close();
+
+    if state.work_dir.get() != jump.dir {
+        state.jumplist.record(view::current_jump(&state));
+    }
+
+    open(state, jump);
+}
@@ -0,0 +1,169 @@
+use crate::{
+    proc::CommandContext,
+    state::{ListItem, ListKind, State},
+};
+use std::sync::Arc;
+
+pub fn open(state: &State, kind: ListKind, items: Vec<ListItem>) {
+    use crate::state::Mode;
+
+    let list = &state.list;
+
+    if list.kind() != Some(kind) {
+        list.cursor.reset();
+        list.selection.disable();
+    }
+
+    list.open(kind, items);
+    state.file_view.selection.disable();
+    state.mode.switch(Mode::List);
+
+    log::info!("The list is opened");
+}
+
+pub fn close(state: Arc<State>) {
+    use crate::state::Mode;
+
+    if let Some(ListKind::JobErrors(_)) = state.list.kind() {
+        super::job::open(state);
+
+        return;
+    }
+
+    if let Some(ListKind::Find | ListKind::Grep) = state.list.kind() {
+        state.finder.cancel();
+    }
+
+    state.list.close();
+    state.mode.switch(Mode::Normal);
+
+    log::info!("The list is closed");
+}
+
+fn select_cursor_pos(state: &State) {
+    state.list.selection.select(state.list.cursor.current());
+}
+
+pub fn move_cursor(state: Arc<State>, ctx: CommandContext, positive: bool) {
+    let cursor = &state.list.cursor;
+    let point = ctx.prenum.unwrap_or(1);
+
+    if positive {
+        cursor.shift_p(point);
+        log::info!("List cursor move down by {point}");
+    } else {
+        cursor.shift_n(point);
+        log::info!("List cursor move up by {point}");
+    }
+
+    select_cursor_pos(&state);
+}
+
+pub fn move_cursor_too(state: Arc<State>, positive: bool) {
+    let cursor = &state.list.cursor;
+    let point = cursor.len();
+
+    if positive {
+        cursor.shift_p(point);
+        log::info!("List cursor move to bottom");
+    } else {
+        cursor.shift_n(point);
+        log::info!("List cursor move to top");
+    }
+
+    select_cursor_pos(&state);
+}
+
+pub fn move_page(state: Arc<State>, ctx: CommandContext, positive: bool) {
+    let cursor = &state.list.cursor;
+    let page_len = state.term_size.load().height.saturating_sub(5) as usize;
+    let point = page_len * ctx.prenum.unwrap_or(1);
+
+    if positive {
+        cursor.shift_p(point);
+        log::info!("List pages plus {point}");
+    } else {
+        cursor.shift_n(point);
+        log::info!("List pages minus {point}");
+    }
+
+    select_cursor_pos(&state);
+}
+
+pub fn toggle_vis(state: Arc<State>) {
+    let selection = &state.list.selection;
+
+    if selection.is_enable() {
+        selection.disable();
+        log::info!("The list selector is disabled");
+    } else {
+        selection.enable(state.list.cursor.current());
+        log::info!("The list selector is enabled");
+    }
+}
+
+pub fn targets(state: &State) -> Vec<ListItem> {
+    let items = state.list.items();
+    let selected = state.list.selection.collect();
+
+    if selected.is_empty() {
+        items
+            .get(state.list.cursor.current())
+            .cloned()
+            .into_iter()
+            .collect()
+    } else {
+        items
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| selected.contains(i))
+            .map(|(_, item)| item.clone())
+            .collect()
+    }
+}
+
+pub fn enter(state: Arc<State>) {
+    use super::{find, grep, job, jumplist, mark, trash};
+    use tokio::task;
+
+    match state.list.kind() {
+        Some(ListKind::Trash) => {
+            task::spawn_blocking(move || trash::restore(state));
+        }
+        Some(ListKind::Jobs) => job::show_errors(state),
+        Some(ListKind::Find) => find::jump(state),
+        Some(ListKind::Grep) => grep::open(state),
+        Some(ListKind::Marks) => mark::enter(state),
+        Some(ListKind::History) => jumplist::enter(state),
+        Some(ListKind::JobErrors(_)) | None => {}
+    }
+}
+
+pub fn delete(state: Arc<State>) {
+    use super::{input, job, mark};
+
+    match state.list.kind() {
+        Some(ListKind::Trash) => input::ask_purge(state),
+        Some(ListKind::Jobs) => job::cancel(state),
+        Some(ListKind::Marks) => mark::delete(state),
+        Some(ListKind::JobErrors(_) | ListKind::Find | ListKind::Grep | ListKind::History)
+        | None => {}
+    }
+}
+
+pub fn clear(state: Arc<State>) {
+    use super::{input, job};
+
+    match state.list.kind() {
+        Some(ListKind::Trash) => input::ask_empty_trash(state),
+        Some(ListKind::Jobs) => job::clear(state),
+        Some(
+            ListKind::JobErrors(_)
+            | ListKind::Find
+            | ListKind::Grep
+            | ListKind::Marks
+            | ListKind::History,
+        )
+        | None => {}
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{
+    component::Mark,
+    state::{ListItem, ListKind, ListPayload, State},
+};
+use std::sync::Arc;
+
+pub fn set(state: Arc<State>, c: char) {
+    let dir = state.work_dir.get();
+    let focus = state
+        .entries()
+        .get(state.file_view.cursor.current())
+        .map(|entry| entry.path.clone());
+
+    log::info!("Set the mark '{c}' to '{}'", dir.to_string_lossy());
+
+    state.marks.set(c, Mark { dir, focus });
+    state.sidemenu.resize(state.marks.len());
+
+    crate::log!("Mark '{c}' set");
+}
+
+pub fn jump(state: Arc<State>, c: char) {
+    let Some(mark) = state.marks.get(c) else {
+        crate::log!("Mark '{c}' is not set");
+
+        return;
+    };
+
+    open(state, c, mark);
+}
+
+pub(super) fn open(state: Arc<State>, c: char, mark: Mark) {
+    use super::view;
+
+    if !mark.dir.is_dir() {
+        crate::log!("Mark '{c}' is not exists");
+        log::warn!(
+            "The mark '{c}' is not exists: {}",
+            mark.dir.to_string_lossy()
+        );
+
+        return;
+    }
+
+    log::info!("Jump to the mark '{c}'");
+
+    view::move_dir(state.clone(), &mark.dir);
+
+    if let Some(focus) = mark.focus
+        && let Some(pos) = state.entries().iter().position(|entry| entry.path == focus)
+    {
+        state.file_view.cursor.shift_p(pos);
+    }
+}
+
+fn collect_items(state: &State) -> Vec<ListItem> {
+    state
+        .marks
+        .list()
+        .into_iter()
+        .map(|(c, mark)| ListItem {
+            payload: ListPayload::Mark(c),
+            label: mark
+                .focus
+                .as_ref()
+                .unwrap_or(&mark.dir)
+                .to_string_lossy()
+                .to_string(),
+            detail: format!("'{c}"),
+            path: mark.dir,
+        })
+        .collect()
+}
+
+pub fn open_list(state: Arc<State>) {
+    use super::list;
+
+    log::info!("Open the marks");
+
+    list::open(&state, ListKind::Marks, collect_items(&state));
+}
+
+pub fn enter(state: Arc<State>) {
+    let Some(ListPayload::Mark(c)) = state
+        .list
+        .items()
+        .get(state.list.cursor.current())
+        .map(|item| item.payload.clone())
+    else {
+        return;
+    };
+
+    state.list.close();
+    jump(state, c);
+}
+
+pub fn delete(state: Arc<State>) {
+    use super::list;
+
+    for c in list::targets(&state)
+        .iter()
+        .filter_map(|item| match item.payload {
+            ListPayload::Mark(c) => Some(c),
+            _ => None,
+        })
+    {
+        log::info!("Delete the mark '{c}'");
+
+        state.marks.remove(c);
+        crate::log!("Mark '{c}' deleted");
+    }
+
+    state.sidemenu.resize(state.marks.len());
+    state.list.selection.disable();
+    state.list.open(ListKind::Marks, collect_items(&state));
+}
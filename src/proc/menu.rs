@@ -1,4 +1,4 @@
-use crate::{proc::CommandContext, state::State};
+use crate::{config::MenuElement, proc::CommandContext, state::State};
 use std::sync::Arc;
 
 pub fn toggle_menu_open(state: Arc<State>) {
@@ -58,13 +58,18 @@ pub fn move_cursor_too(state: Arc<State>, positive: bool) {
 }
 
 pub fn enter(state: Arc<State>) {
-    use super::view;
-    use crate::config;
+    use super::{mark, view};
 
     let cursor = &state.sidemenu.cursor;
-    let config = config::get();
+    let elements = state.sidemenu.elements();
+
+    let Some(element) = elements.get(cursor.current()) else {
+        let index = cursor.current() - elements.len();
+
+        if let Some((c, target)) = state.marks.list().into_iter().nth(index) {
+            mark::open(state, c, target);
+        }
 
-    let Some(element) = config.menu_elements.get(cursor.current()) else {
         return;
     };
 
@@ -90,3 +95,102 @@ pub fn enter(state: Arc<State>) {
 
     view::move_dir(state, path);
 }
+
+fn save(elements: &[MenuElement]) {
+    use crate::config;
+
+    if let Err(e) = config::save_menu_items(elements) {
+        log::warn!("Couldn't save the sidemenu\n\t{e}");
+        crate::log!("Failed to save the sidemenu: {e}");
+    }
+}
+
+pub fn pin(state: Arc<State>) {
+    use crate::misc;
+
+    let wd = state.work_dir.get();
+    let tag = misc::entry_name(&wd);
+
+    if state
+        .sidemenu
+        .elements()
+        .iter()
+        .any(|element| element.path == wd)
+    {
+        crate::log!("'{}' is already pinned", wd.to_string_lossy());
+
+        return;
+    }
+
+    let tag = if tag.is_empty() {
+        String::from("/")
+    } else {
+        tag
+    };
+    let elements = state
+        .sidemenu
+        .update(|elements| elements.push(MenuElement::new(&tag, &wd)));
+
+    state.sidemenu.resize(state.marks.len());
+    save(&elements);
+
+    log::info!("Pin the '{}' to the sidemenu", wd.to_string_lossy());
+    crate::log!("'{tag}' pinned");
+}
+
+pub fn remove(state: Arc<State>) {
+    let cursor = &state.sidemenu.cursor;
+    let index = cursor.current();
+    let len = state.sidemenu.len();
+
+    if index < len {
+        let mut removed = None;
+        let elements = state
+            .sidemenu
+            .update(|elements| removed = Some(elements.remove(index)));
+
+        save(&elements);
+
+        if let Some(removed) = removed {
+            log::info!("Remove the '{}' from the sidemenu", removed.tag);
+            crate::log!("'{}' removed", removed.tag);
+        }
+    } else if let Some((c, _)) = state.marks.list().into_iter().nth(index - len) {
+        state.marks.remove(c);
+
+        log::info!("Remove the mark '{c}' from the sidemenu");
+        crate::log!("Mark '{c}' deleted");
+    }
+
+    state.sidemenu.resize(state.marks.len());
+}
+
+pub fn move_item(state: Arc<State>, positive: bool) {
+    let cursor = &state.sidemenu.cursor;
+    let index = cursor.current();
+    let len = state.sidemenu.len();
+
+    let target = if positive {
+        index + 1
+    } else {
+        index.saturating_sub(1)
+    };
+
+    if index >= len || target >= len || target == index {
+        return;
+    }
+
+    let elements = state
+        .sidemenu
+        .update(|elements| elements.swap(index, target));
+
+    if positive {
+        cursor.next();
+    } else {
+        cursor.previous();
+    }
+
+    save(&elements);
+
+    log::info!("Move the sidemenu item {index} to {target}");
+}
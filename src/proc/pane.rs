@@ -0,0 +1,88 @@
+use crate::state::State;
+use std::{path::PathBuf, sync::Arc};
+
+pub fn toggle_dual(state: Arc<State>) {
+    let flag = &state.flag;
+
+    if flag.is_dual_pane.get() {
+        flag.is_dual_pane.down();
+        flag.is_right_focused.down();
+        log::info!("Dual pane disabled");
+    } else {
+        flag.is_dual_pane.up();
+        flag.is_miller.down();
+        log::info!("Dual pane enabled");
+    }
+}
+
+pub fn switch_focus(state: Arc<State>) {
+    use super::tab;
+
+    let flag = &state.flag;
+
+    if !flag.is_dual_pane.get() {
+        return;
+    }
+
+    let tab = state.pane.swap(tab::take(&state));
+
+    tab::restore(&state, tab);
+
+    if flag.is_right_focused.get() {
+        flag.is_right_focused.down();
+    } else {
+        flag.is_right_focused.up();
+    }
+
+    log::info!("Switch the pane focus");
+}
+
+fn targets(state: &State) -> Vec<PathBuf> {
+    let entries = state.entries();
+    let file_view = &state.file_view;
+
+    if file_view.selection.is_enable() {
+        return file_view.selection.paths();
+    }
+
+    entries
+        .get(file_view.cursor.current())
+        .map(|entry| vec![entry.path.clone()])
+        .unwrap_or_default()
+}
+
+fn transfer(state: Arc<State>, is_cut: bool) {
+    use super::input;
+    use crate::state::Mode;
+
+    if !state.flag.is_dual_pane.get() {
+        return;
+    }
+
+    let dir = state.pane.dir();
+
+    if dir == state.work_dir.get() {
+        crate::log!("Both panes are in the same directory");
+
+        return;
+    }
+
+    let items = targets(&state);
+
+    if items.is_empty() {
+        return;
+    }
+
+    state.file_view.selection.disable();
+    state.mode.switch(Mode::Normal);
+
+    input::transfer(state, dir, items, is_cut);
+}
+
+pub fn copy(state: Arc<State>) {
+    transfer(state, false);
+}
+
+pub fn move_items(state: Arc<State>) {
+    transfer(state, true);
+}
@@ -0,0 +1,155 @@
+use crate::{highlight::Line, proc::CommandContext, state::State};
+use std::{path::Path, sync::Arc};
+
+const PEEK_LIMIT: u64 = 1024 * 1024;
+
+fn read_head(path: &Path) -> std::io::Result<(Vec<u8>, bool)> {
+    use std::{fs::File, io::Read};
+
+    let mut buf = vec![];
+    let len = path.metadata()?.len();
+
+    File::open(path)?.take(PEEK_LIMIT).read_to_end(&mut buf)?;
+
+    Ok((buf, len > PEEK_LIMIT))
+}
+
+fn hex_lines(buf: &[u8]) -> Vec<Line> {
+    use crate::highlight::Token;
+
+    buf.chunks(16)
+        .enumerate()
+        .map(|(i, row)| {
+            let hex = (0..16)
+                .map(|col| {
+                    let byte = row
+                        .get(col)
+                        .map(|byte| format!("{byte:02x} "))
+                        .unwrap_or(String::from("   "));
+
+                    if col == 7 { format!("{byte} ") } else { byte }
+                })
+                .collect::<String>();
+            let ascii = row
+                .iter()
+                .map(|&byte| {
+                    if byte.is_ascii_graphic() || byte == b' ' {
+                        byte as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+
+            vec![
+                (Token::Comment, format!("{:08x}  ", i * 16)),
+                (Token::Number, hex),
+                (Token::String, format!(" |{ascii}|")),
+            ]
+        })
+        .collect()
+}
+
+pub fn open(state: Arc<State>) {
+    use crate::{
+        highlight::{self, Token},
+        misc,
+        state::Mode,
+    };
+    use si_scale::helpers;
+
+    let entries = state.entries();
+
+    let Some(target) = entries
+        .get(state.file_view.cursor.current())
+        .map(|entry| &entry.path)
+    else {
+        return;
+    };
+
+    if !target.is_file() {
+        return;
+    }
+
+    let name = misc::entry_name(target);
+
+    log::info!("Peek the {}", target.to_string_lossy());
+
+    let (buf, is_truncated) = match read_head(target) {
+        Ok(head) => head,
+        Err(e) => {
+            log::warn!("Read the '{name}' is failed\n\t{}", e.kind());
+            crate::log!("Failed to peek the '{name}': {}", e.kind());
+
+            return;
+        }
+    };
+
+    let (info, mut lines) = match highlight::decode_text(&buf) {
+        Some(text) => {
+            let syntax = highlight::detect(target, &text);
+
+            (
+                syntax
+                    .map(|syntax| syntax.name)
+                    .unwrap_or("Text")
+                    .to_string(),
+                highlight::highlight(syntax, &text),
+            )
+        }
+        None => (String::from("Hex"), hex_lines(&buf)),
+    };
+
+    if is_truncated {
+        lines.push(vec![(
+            Token::Comment,
+            format!("-- truncated at {} --", helpers::bytes1(PEEK_LIMIT as f64)),
+        )]);
+    }
+
+    state.peek.open(target.clone(), info, lines);
+    state.file_view.selection.disable();
+    state.mode.switch(Mode::Peek);
+}
+
+pub fn close(state: Arc<State>) {
+    use crate::state::Mode;
+
+    state.peek.close();
+    state.mode.switch(Mode::Normal);
+
+    log::info!("The peek is closed");
+}
+
+pub fn scroll(state: Arc<State>, ctx: CommandContext, positive: bool) {
+    let scroll = &state.peek.scroll;
+    let point = ctx.prenum.unwrap_or(1);
+
+    if positive {
+        scroll.shift_p(point);
+    } else {
+        scroll.shift_n(point);
+    }
+}
+
+pub fn scroll_too(state: Arc<State>, positive: bool) {
+    let scroll = &state.peek.scroll;
+
+    if positive {
+        scroll.shift_p(scroll.len());
+    } else {
+        scroll.reset();
+    }
+}
+
+pub fn scroll_page(state: Arc<State>, ctx: CommandContext, positive: bool) {
+    let scroll = &state.peek.scroll;
+    let page_len = state.term_size.load().height.saturating_sub(5) as usize;
+    let point = page_len * ctx.prenum.unwrap_or(1);
+
+    if positive {
+        scroll.shift_p(point);
+    } else {
+        scroll.shift_n(point);
+    }
+}
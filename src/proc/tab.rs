@@ -0,0 +1,111 @@
+use crate::{
+    component::CursorCache,
+    proc::CommandContext,
+    state::{State, Tab},
+};
+use std::sync::Arc;
+
+pub(super) fn take(state: &State) -> Tab {
+    let cursor = &state.file_view.cursor;
+    let selection = &state.file_view.selection;
+
+    Tab {
+        dir: state.work_dir.get(),
+        focus: state
+            .entries()
+            .get(cursor.current())
+            .map(|entry| entry.path.clone()),
+        cursor: cursor.current(),
+        selection: selection
+            .is_enable()
+            .then(|| (selection.anchor(), selection.paths())),
+        cursor_cache: state.file_view.cursor_cache.replace(CursorCache::new()),
+        grep: state.grep.load(),
+        filter: state.filter.load(),
+    }
+}
+
+pub(super) fn restore(state: &State, tab: Tab) {
+    use crate::state::Mode;
+
+    let file_view = &state.file_view;
+
+    state.work_dir.store(&tab.dir);
+    state.grep.update(tab.grep);
+    state.filter.update(tab.filter);
+    file_view.cursor_cache.replace(tab.cursor_cache);
+
+    let entries = state.entries();
+    let pos = tab
+        .focus
+        .and_then(|focus| entries.iter().position(|entry| entry.path == focus))
+        .unwrap_or(tab.cursor);
+
+    file_view.cursor.resize(entries.len());
+    file_view.cursor.reset();
+    file_view.cursor.shift_p(pos);
+
+    if let Some((anchor, paths)) = tab.selection {
+        file_view.selection.restore(&entries, anchor, paths);
+        state.mode.switch(Mode::Visual);
+    } else {
+        file_view.selection.disable();
+        state.mode.switch(Mode::Normal);
+    }
+}
+
+pub fn open(state: Arc<State>) {
+    use crate::state::Mode;
+
+    state.tabs.open(take(&state));
+
+    state.grep.clear();
+    state.filter.clear();
+    state.file_view.selection.disable();
+    state.mode.switch(Mode::Normal);
+
+    log::info!("Open a new tab {}", state.tabs.current() + 1);
+}
+
+pub fn close(state: Arc<State>) {
+    let Some(tab) = state.tabs.close() else {
+        crate::log!("The last tab can't be closed");
+
+        return;
+    };
+
+    restore(&state, tab);
+
+    log::info!("Close the tab, now on the tab {}", state.tabs.current() + 1);
+}
+
+fn switch(state: &State, index: usize) {
+    if index == state.tabs.current() || index >= state.tabs.len() {
+        return;
+    }
+
+    let tab = state.tabs.switch(index, take(state));
+
+    restore(state, tab);
+
+    log::info!("Switch to the tab {}", index + 1);
+}
+
+pub fn next(state: Arc<State>, ctx: CommandContext) {
+    let len = state.tabs.len();
+
+    let index = match ctx.prenum {
+        Some(n) if (1..=len).contains(&n) => n - 1,
+        Some(_) => return,
+        None => (state.tabs.current() + 1) % len,
+    };
+
+    switch(&state, index);
+}
+
+pub fn prev(state: Arc<State>, ctx: CommandContext) {
+    let len = state.tabs.len();
+    let point = ctx.prenum.unwrap_or(1) % len;
+
+    switch(&state, (state.tabs.current() + len - point) % len);
+}
@@ -0,0 +1,160 @@
+use crate::{
+    state::{ListItem, State},
+    trash::TrashEntry,
+};
+use std::sync::Arc;
+
+fn to_item(entry: TrashEntry, size: Option<u64>) -> ListItem {
+    use si_scale::helpers;
+
+    ListItem {
+        label: entry.original.to_string_lossy().to_string(),
+        detail: format!(
+            "{} {:>8}",
+            entry
+                .deleted_at
+                .map(|date| date.format("%y %m/%d %H:%M").to_string())
+                .unwrap_or(String::from("       x      ")),
+            size.map(|size| helpers::bytes1(size as f64))
+                .unwrap_or(String::from("-"))
+        ),
+        path: entry.trashed,
+        ..Default::default()
+    }
+}
+
+fn show(state: &Arc<State>, entries: Vec<TrashEntry>) {
+    use super::list;
+    use crate::state::ListKind;
+
+    let is_measured = entries.iter().all(|entry| entry.size.is_some());
+    let items = entries
+        .into_iter()
+        .map(|entry| {
+            let size = entry.size;
+
+            to_item(entry, size)
+        })
+        .collect();
+
+    list::open(state, ListKind::Trash, items);
+
+    if !is_measured {
+        measure(state);
+    }
+}
+
+fn measure(state: &Arc<State>) {
+    use crate::{state::ListKind, trash};
+    use std::collections::HashMap;
+
+    let st = state.clone();
+
+    state
+        .jobs
+        .spawn(String::from("Measure the trash"), move |job| {
+            let mut sizes = HashMap::new();
+
+            for entry in trash::list().iter().filter(|entry| entry.size.is_none()) {
+                if job.is_cancelled() {
+                    return;
+                }
+
+                sizes.insert(entry.trashed.clone(), trash::entry_size(&entry.trashed));
+            }
+
+            if st.list.kind() != Some(ListKind::Trash) {
+                return;
+            }
+
+            let items = trash::list()
+                .into_iter()
+                .map(|entry| {
+                    let size = entry.size.or_else(|| sizes.get(&entry.trashed).copied());
+
+                    to_item(entry, size)
+                })
+                .collect();
+
+            st.list.open(ListKind::Trash, items);
+        });
+}
+
+pub fn open(state: Arc<State>) {
+    use crate::trash;
+
+    log::info!("Open the trash");
+
+    show(&state, trash::list());
+}
+
+pub fn reload(state: &Arc<State>) {
+    use crate::trash;
+
+    state.list.selection.disable();
+    show(state, trash::list());
+}
+
+pub fn restore(state: Arc<State>) {
+    use super::input::paste;
+    use crate::{config, misc, trash};
+    use std::{fs, path::Path};
+
+    let targets = super::list::targets(&state);
+    let mut count = 0usize;
+
+    for item in targets.iter() {
+        let trashed = &item.path;
+
+        let Some(original) = trash::original_path(trashed) else {
+            log::warn!(
+                "The original path of {} cannot read",
+                trashed.to_string_lossy()
+            );
+
+            continue;
+        };
+
+        let parent = original.parent().unwrap_or(Path::new("/"));
+
+        if !parent.exists()
+            && let Err(e) = fs::create_dir_all(parent)
+        {
+            log::warn!(
+                "Create the {} is failed\n\t{}",
+                parent.to_string_lossy(),
+                e.kind()
+            );
+
+            continue;
+        }
+
+        let mut entry = misc::entry_name(&original);
+
+        paste::recursive_suffix(parent, &mut entry, &config::get().paste_similar_suffix);
+
+        let into = parent.join(&entry);
+
+        log::info!(
+            "Restore the {} to {}",
+            trashed.to_string_lossy(),
+            into.to_string_lossy()
+        );
+
+        match trash::restore(trashed, &into) {
+            Ok(_) => count += 1,
+            Err(e) => {
+                log::warn!("Restore the '{entry}' is failed\n\t{}", e.kind());
+                crate::log!("Failed to restore the '{entry}': {}", e.kind());
+            }
+        }
+    }
+
+    if count == 1 && targets.len() == 1 {
+        crate::log!("'{}' restored", targets[0].label);
+    } else if count != 0 {
+        crate::log!("{count} items restored");
+    }
+
+    reload(&state);
+}
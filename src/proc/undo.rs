@@ -0,0 +1,158 @@
+use crate::{
+    component::{Operation, Resolution, Stamp},
+    state::State,
+};
+use std::{fs, io, path::Path, sync::Arc};
+
+pub fn undo(state: Arc<State>) {
+    use super::view;
+
+    let Some(step) = state.journal.take_undo() else {
+        crate::log!("Already at oldest change");
+
+        return;
+    };
+
+    log::info!("Undo the operations: \n{step:?}");
+
+    let len = step.len();
+    let mut reverted = vec![];
+
+    for op in step.into_iter().rev() {
+        match revert(&op) {
+            Ok(_) => reverted.push(op),
+            Err(e) => {
+                log::warn!("Undo a {op:?} is failed\n\t{}", e.kind());
+            }
+        }
+    }
+
+    let count = reverted.len();
+
+    reverted.reverse();
+    state.journal.push_redo(reverted);
+
+    if count == len {
+        log::info!("The {count} operations successfully undone");
+        crate::log!("{count} changes undone");
+    } else {
+        crate::log!("{count} changes undone, {} cannot undo", len - count);
+    }
+
+    view::initialize(&state);
+}
+
+pub fn redo(state: Arc<State>) {
+    use super::view;
+
+    let Some(step) = state.journal.take_redo() else {
+        crate::log!("Already at newest change");
+
+        return;
+    };
+
+    log::info!("Redo the operations: \n{step:?}");
+
+    let len = step.len();
+    let mut replayed = vec![];
+
+    for op in step.into_iter() {
+        match replay(&op) {
+            Ok(op) => replayed.push(op),
+            Err(e) => {
+                log::warn!("Redo a {op:?} is failed\n\t{}", e.kind());
+            }
+        }
+    }
+
+    let count = replayed.len();
+
+    state.journal.push_undo(replayed);
+
+    if count == len {
+        log::info!("The {count} operations successfully redone");
+        crate::log!("{count} changes redone");
+    } else {
+        crate::log!("{count} changes redone, {} cannot redo", len - count);
+    }
+
+    view::initialize(&state);
+}
+
+fn revert(op: &Operation) -> io::Result<()> {
+    use crate::{misc, trash};
+
+    match op {
+        Operation::Create { path, stamp, .. } => trash_unchanged(path, stamp),
+        Operation::Rename { from, to } => {
+            if from.exists() {
+                return Err(io::ErrorKind::AlreadyExists.into());
+            }
+
+            misc::move_item(to, from)
+        }
+        Operation::Delete { path, trashed } => {
+            let Some(trashed) = trashed else {
+                return Err(io::ErrorKind::Unsupported.into());
+            };
+
+            trash::restore(trashed, path)
+        }
+        Operation::Copy { to, stamp, .. } => trash_unchanged(to, stamp),
+    }
+}
+
+fn trash_unchanged(path: &Path, stamp: &Option<Stamp>) -> io::Result<()> {
+    use crate::trash;
+
+    if Stamp::of(path) != *stamp {
+        log::warn!(
+            "The {} is changed after the operation",
+            path.to_string_lossy()
+        );
+
+        return Err(io::ErrorKind::InvalidData.into());
+    }
+
+    trash::put(path).map(|_| ())
+}
+
+fn replay(op: &Operation) -> io::Result<Operation> {
+    use super::input::{delete, paste};
+    use crate::misc;
+
+    match op {
+        Operation::Create { path, is_dir, .. } => {
+            if *is_dir {
+                fs::create_dir(path)?;
+            } else {
+                fs::File::create_new(path)?;
+            }
+
+            Ok(Operation::Create {
+                path: path.clone(),
+                is_dir: *is_dir,
+                stamp: Stamp::of(path),
+            })
+        }
+        Operation::Rename { from, to } => {
+            if to.exists() {
+                return Err(io::ErrorKind::AlreadyExists.into());
+            }
+
+            misc::move_item(from, to)?;
+
+            Ok(op.clone())
+        }
+        Operation::Delete { path, .. } => {
+            delete::delete_item(path)?.ok_or(io::ErrorKind::NotFound.into())
+        }
+        Operation::Copy { from, to, .. } => {
+            let mut ops = vec![];
+
+            paste::copy_item(from, to, Resolution::Skip, &mut ops, None);
+
+            ops.pop().ok_or(io::ErrorKind::AlreadyExists.into())
+        }
+    }
+}
@@ -1,5 +1,8 @@
-use crate::{proc::CommandContext, state::State};
-use std::{path::Path, sync::Arc};
+use crate::{component::Jump, proc::CommandContext, state::State};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 pub fn refresh(state: Arc<State>) {
     initialize(&state);
@@ -9,26 +12,66 @@ pub fn refresh(state: Arc<State>) {
     state.canvas_hashes.refresh();
 }
 
+pub fn reset(state: Arc<State>) {
+    use crate::proc::input::filter;
+
+    filter::clear(&state);
+    refresh(state);
+}
+
 pub fn initialize(state: &State) {
-    use crate::{misc, state::Mode};
+    use crate::state::Mode;
 
     log::info!("Reset the viewer enviroment to init");
 
     state.file_view.selection.disable();
-    state.mode.switch(Mode::Normal);
     state.input.disable();
 
-    state
-        .file_view
-        .cursor
-        .resize(misc::child_files_len(&state.work_dir.get()));
+    if state.list.is_open() {
+        state.mode.switch(Mode::List);
+    } else {
+        state.mode.switch(Mode::Normal);
+    }
+
+    reload(state);
+}
+
+pub fn reload(state: &State) {
+    state.pane.invalidate();
+    reanchor(state, || state.snapshot.invalidate());
 }
 
-fn select_cursor_pos(state: &State) {
+pub fn reanchor<F: FnOnce()>(state: &State, update: F) {
+    let file_view = &state.file_view;
+    let cursor = &file_view.cursor;
+    let selection = &file_view.selection;
+
+    let prev = state.snapshot.cached();
+    let path_at = |idx: usize| prev.get(idx).map(|entry| entry.path.clone());
+    let focused = path_at(cursor.current());
+
+    update();
+
+    let entries = state.entries();
+    let resolve = |path: Option<PathBuf>, fallback: usize| {
+        path.and_then(|path| entries.iter().position(|entry| entry.path == path))
+            .unwrap_or(fallback.min(entries.len().saturating_sub(1)))
+    };
+
+    let pos = resolve(focused, cursor.current());
+
+    cursor.resize(entries.len());
+    cursor.reset();
+    cursor.shift_p(pos);
+
+    selection.resolve(&entries);
+}
+
+pub(super) fn select_cursor_pos(state: &State) {
     state
         .file_view
         .selection
-        .select(state.file_view.cursor.current());
+        .select(&state.entries(), state.file_view.cursor.current());
 }
 
 pub fn move_cursor(state: Arc<State>, ctx: CommandContext, positive: bool) {
@@ -78,7 +121,25 @@ pub fn move_page(state: Arc<State>, ctx: CommandContext, positive: bool) {
 }
 
 pub fn move_dir(state: Arc<State>, path: &Path) {
-    use crate::{misc, state::Mode};
+    if state.work_dir.get() != path {
+        state.jumplist.record(current_jump(&state));
+    }
+
+    change_dir(state, path);
+}
+
+pub(super) fn current_jump(state: &State) -> Jump {
+    Jump {
+        dir: state.work_dir.get(),
+        focus: state
+            .entries()
+            .get(state.file_view.cursor.current())
+            .map(|entry| entry.path.clone()),
+    }
+}
+
+pub(super) fn change_dir(state: Arc<State>, path: &Path) {
+    use crate::state::Mode;
 
     log::info!("Change working path");
     log::info!("Before: {}", state.work_dir.get().to_string_lossy());
@@ -86,69 +147,79 @@ pub fn move_dir(state: Arc<State>, path: &Path) {
 
     state.mode.switch(Mode::Normal);
     state.work_dir.store(path);
+    state.filter.clear();
 
     let cursor = &state.file_view.cursor;
 
     state.file_view.selection.disable();
 
-    cursor.resize(misc::child_files_len(path));
+    cursor.resize(state.entries().len());
     cursor.reset();
 }
 
-pub fn move_parent(state: Arc<State>) {
-    use crate::misc;
+pub fn sync(state: &State) {
+    state.watcher.watch(&state.work_dir.get());
+
+    if state.watcher.take_changed() {
+        reload(state);
+    }
 
+    if state.flag.is_dual_pane.get() {
+        state.pane.sync();
+    }
+}
+
+pub fn move_parent(state: Arc<State>) {
     let wd = state.work_dir.get();
 
     if wd == Path::new("/") {
         return;
     }
 
-    let child_files = misc::sorted_child_files(&wd);
     let cursor = &state.file_view.cursor;
-    let recorded_path = child_files.get(cursor.current());
+    let recorded_path = state
+        .entries()
+        .get(cursor.current())
+        .map(|entry| entry.path.clone());
     let parent_path = wd.parent().unwrap_or(&wd);
 
     move_dir(state.clone(), parent_path);
 
     if let Some(record) = recorded_path {
-        state.file_view.cursor_cache.wrap_node(record);
+        state.file_view.cursor_cache.wrap_node(&record);
         log::info!("Wrap for cursor cache");
     }
 
-    let child_files = misc::sorted_child_files(parent_path);
-
-    if let Some(pos) = child_files.into_iter().position(|p| p == wd) {
+    if let Some(pos) = state.entries().iter().position(|entry| entry.path == wd) {
         cursor.shift_p(pos);
         log::info!("Cursor reset to {pos}");
     }
 }
 
 pub fn attach_child(state: Arc<State>) {
-    use crate::{config, misc, tui};
-    use std::process::{Command, Stdio};
-    use tokio::task;
+    let entries = state.entries();
 
-    let wd = state.work_dir.get();
-    let child_files = misc::sorted_child_files(&wd);
-
-    if child_files.is_empty() {
+    if entries.is_empty() {
         return;
     }
 
     let cursor = &state.file_view.cursor;
 
-    let Some(target_path) = child_files.get(cursor.current()) else {
+    let Some(target) = entries.get(cursor.current()) else {
         return;
     };
+    let target_path = &target.path;
 
-    if target_path.is_dir() {
+    if target.is_dir() {
         move_dir(state.clone(), target_path);
 
-        let child_files = misc::sorted_child_files(target_path);
         let cursor_cache = &state.file_view.cursor_cache;
 
-        if let Some(pos) = child_files.iter().position(|e| cursor_cache.inner_equal(e)) {
+        if let Some(pos) = state
+            .entries()
+            .iter()
+            .position(|entry| cursor_cache.inner_equal(&entry.path))
+        {
             cursor.shift_p(pos);
             cursor_cache.unwrap_surface();
             log::info!("Cursor reset to {pos}");
@@ -157,56 +228,140 @@ pub fn attach_child(state: Arc<State>) {
             cursor_cache.reset();
         }
     } else {
-        let config = config::get();
+        open_file(&state, target_path, None);
+    }
+}
+
+pub fn open_file(state: &State, path: &Path, line: Option<usize>) {
+    use crate::{config, misc, tui};
+    use std::process::{Command, Stdio};
 
-        let info = config
-            .hijack
-            .get(target_path)
-            .unwrap_or(config.hijack.default_ed());
-        let hijack_tui = info.hijack;
-        let exec = &info.cmd;
+    let config = config::get();
 
-        log::info!("Attach '{}'", target_path.to_string_lossy());
+    let info = config
+        .hijack
+        .get(path)
+        .unwrap_or(config.hijack.default_ed());
+    let hijack_tui = info.hijack;
+    let exec = &info.cmd;
 
-        if hijack_tui {
-            tui::disable();
+    log::info!("Attach '{}'", path.to_string_lossy());
 
-            log::info!("Exec the {}", exec.cmd);
+    if hijack_tui {
+        tui::disable();
 
-            Command::new(&exec.cmd)
-                .args(&exec.args)
-                .arg(target_path)
-                .status()
-                .ok();
+        log::info!("Exec the {}", exec.cmd);
 
-            log::info!("Back to endolphine, from {}", exec.cmd);
+        Command::new(&exec.cmd)
+            .args(&exec.args)
+            .args(line.and_then(|line| exec.line_arg(line)))
+            .arg(path)
+            .status()
+            .ok();
 
-            tui::enable();
+        log::info!("Back to endolphine, from {}", exec.cmd);
 
-            state.canvas_hashes.refresh();
-        } else {
-            let target_path = target_path.clone();
-            let state = state.clone();
+        tui::enable();
 
-            log::info!("Exec the {}", exec.cmd);
+        state.canvas_hashes.refresh();
+    } else {
+        let path = path.to_path_buf();
 
-            task::spawn_blocking(move || {
-                state.proc_counter.increment();
+        log::info!("Exec the {}", exec.cmd);
 
-                Command::new(&exec.cmd)
+        state.jobs.spawn(
+            format!("Open {} with {}", misc::entry_name(&path), exec.cmd),
+            move |job| {
+                use std::{thread, time::Duration};
+
+                let child = Command::new(&exec.cmd)
                     .args(&exec.args)
-                    .arg(&target_path)
+                    .args(line.and_then(|line| exec.line_arg(line)))
+                    .arg(&path)
                     .stdout(Stdio::null())
                     .stderr(Stdio::null())
-                    .status()
-                    .ok();
+                    .spawn();
+
+                let mut child = match child {
+                    Ok(child) => child,
+                    Err(e) => {
+                        job.error(e.kind());
+                        log::warn!("Exec the {} is failed\n\t{}", exec.cmd, e.kind());
+
+                        return;
+                    }
+                };
+
+                loop {
+                    match child.try_wait() {
+                        Ok(Some(status)) => {
+                            if !status.success() {
+                                job.error(status);
+                            }
+
+                            break;
+                        }
+                        Ok(None) if job.is_cancelled() => {
+                            log::info!("Kill the {}", exec.cmd);
+
+                            child.kill().ok();
+                            child.wait().ok();
+
+                            break;
+                        }
+                        Ok(None) => thread::sleep(Duration::from_millis(100)),
+                        Err(e) => {
+                            job.error(e.kind());
+
+                            break;
+                        }
+                    }
+                }
+            },
+        );
+    }
+}
 
-                state.proc_counter.decrement();
-            });
-        }
+pub fn toggle_preview(state: Arc<State>) {
+    let flag = &state.flag.is_preview_opened;
+
+    if flag.get() {
+        flag.down();
+        log::info!("The preview is closed");
+    } else {
+        flag.up();
+        log::info!("The preview is opened");
+    }
+}
+
+pub fn toggle_miller(state: Arc<State>) {
+    let flag = &state.flag;
+
+    if flag.is_miller.get() {
+        flag.is_miller.down();
+        log::info!("Miller columns disabled");
+    } else {
+        flag.is_miller.up();
+        flag.is_dual_pane.down();
+        flag.is_right_focused.down();
+        log::info!("Miller columns enabled");
     }
 }
 
+pub fn toggle_hidden(state: Arc<State>) {
+    let flag = &state.flag.is_hidden_shown;
+
+    reanchor(&state, || {
+        if flag.get() {
+            flag.down();
+            log::info!("The hidden files are hidden");
+        } else {
+            flag.up();
+            log::info!("The hidden files are shown");
+        }
+    });
+}
+
 pub fn toggle_vis(state: Arc<State>) {
     use crate::state::Mode;
 
@@ -217,7 +372,7 @@ pub fn toggle_vis(state: Arc<State>) {
         state.mode.switch(Mode::Normal);
         log::info!("The visual selector is disabled");
     } else {
-        selection.enable(state.file_view.cursor.current());
+        selection.enable(&state.entries(), state.file_view.cursor.current());
         state.mode.switch(Mode::Visual);
         log::info!("The visual selector is enabled");
     }
@@ -1,12 +1,19 @@
 use crate::state::State;
-use std::{io, path::Path, sync::Arc};
+use std::{
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
-pub fn clip_files<P: AsRef<Path>>(files: &[P]) -> io::Result<()> {
-    use crate::{clipboard, config};
+const GNOME_COPIED_FILES: &str = "x-special/gnome-copied-files";
+const CUT_COMMENT: &str = "# cut";
+
+pub fn clip_files<P: AsRef<Path>>(files: &[P], is_cut: bool) -> io::Result<()> {
+    use crate::{clipboard, config, misc};
 
     let files = files
         .iter()
-        .map(|p| format!("file://{}\n", p.as_ref().to_string_lossy()))
+        .map(|p| format!("file://{}\n", misc::encode_path(p.as_ref())))
         .collect::<String>();
 
     let conf = config::get();
@@ -25,18 +32,85 @@ pub fn clip_files<P: AsRef<Path>>(files: &[P]) -> io::Result<()> {
             return Ok(());
         }
 
-        clipboard::clip_native(files, "text/uri-list")
+        if is_cut {
+            clipboard::clip_native_all(&[
+                (&format!("cut\n{files}"), GNOME_COPIED_FILES),
+                (&format!("{CUT_COMMENT}\n{files}"), "text/uri-list"),
+            ])
+        } else {
+            clipboard::clip_native(files, "text/uri-list")
+        }
+    } else if is_cut {
+        clipboard::clip(format!("cut\n{files}"))
     } else {
         clipboard::clip(files)
     }
 }
 
+pub fn clipped_files() -> io::Result<(bool, Vec<PathBuf>)> {
+    use crate::{clipboard, config, misc};
+
+    let content = if config::get().native_cb {
+        let special = clipboard::read_native(GNOME_COPIED_FILES)?;
+
+        if special.starts_with("cut") || special.starts_with("copy") {
+            special
+        } else {
+            clipboard::read_native("text/uri-list")?
+        }
+    } else {
+        clipboard::read()?
+    };
+
+    let is_cut = content
+        .lines()
+        .next()
+        .is_some_and(|line| matches!(line.trim(), "cut" | CUT_COMMENT));
+    let files = content
+        .lines()
+        .filter_map(|s| s.trim().strip_prefix("file://"))
+        .map(misc::decode_path)
+        .filter(|path| path.symlink_metadata().is_ok())
+        .collect::<Vec<_>>();
+
+    Ok((is_cut, files))
+}
+
+pub fn clear_clipped() -> io::Result<()> {
+    use crate::{clipboard, config};
+
+    if config::get().native_cb {
+        clipboard::clip_native("", "text/uri-list")
+    } else {
+        clipboard::clip("")
+    }
+}
+
 pub fn yank(state: Arc<State>) {
+    yank_as(state, false);
+}
+
+pub fn yank_selects(state: Arc<State>) {
+    yank_selects_as(state, false);
+}
+
+pub fn cut(state: Arc<State>) {
+    yank_as(state, true);
+}
+
+pub fn cut_selects(state: Arc<State>) {
+    yank_selects_as(state, true);
+}
+
+fn yank_as(state: Arc<State>, is_cut: bool) {
     use crate::{config, misc};
 
-    let child_files = misc::sorted_child_files(&state.work_dir.get());
+    let entries = state.entries();
 
-    if let Some(target) = child_files.get(state.file_view.cursor.current()) {
+    if let Some(target) = entries
+        .get(state.file_view.cursor.current())
+        .map(|entry| &entry.path)
+    {
         let native = config::get().native_cb;
 
         if native {
@@ -45,10 +119,14 @@ pub fn yank(state: Arc<State>) {
             log::info!("Clipping by endolphine");
         }
 
-        match clip_files(&[target]) {
+        match clip_files(&[target], is_cut) {
             Ok(_) => {
                 log::info!("Now clipboard: \n{}", target.to_string_lossy());
-                crate::log!("Yanked '{}'", misc::entry_name(target));
+                crate::log!(
+                    "{} '{}'",
+                    if is_cut { "Cut" } else { "Yanked" },
+                    misc::entry_name(target)
+                );
             }
             Err(e) => {
                 log::warn!(
@@ -66,17 +144,11 @@ pub fn yank(state: Arc<State>) {
     }
 }
 
-pub fn yank_selects(state: Arc<State>) {
-    use crate::{config, misc, proc::view};
+fn yank_selects_as(state: Arc<State>, is_cut: bool) {
+    use crate::{config, proc::view};
 
-    let child_files = misc::sorted_child_files(&state.work_dir.get());
     let selected = state.file_view.selection.collect();
-
-    let targets = child_files
-        .into_iter()
-        .enumerate()
-        .filter_map(|(i, c)| selected.contains(&i).then_some(c))
-        .collect::<Vec<_>>();
+    let targets = state.file_view.selection.paths();
 
     let native = config::get().native_cb;
 
@@ -86,10 +158,14 @@ pub fn yank_selects(state: Arc<State>) {
         log::info!("Clipping by endolphine");
     }
 
-    match clip_files(&targets) {
+    match clip_files(&targets, is_cut) {
         Ok(_) => {
             log::info!("Now clipboard: \n{targets:?}");
-            crate::log!("Yanked {} items", targets.len());
+            crate::log!(
+                "{} {} items",
+                if is_cut { "Cut" } else { "Yanked" },
+                targets.len()
+            );
         }
         Err(e) => {
             log::warn!("Clip files is failed\n\t{}", e.kind());
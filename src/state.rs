@@ -1,12 +1,20 @@
 use crate::{
     canvas::Rect,
-    component::{Cursor, CursorCache, Input, Selection},
+    component::{
+        ConflictQueue, Cursor, CursorCache, DirSnapshot, Entry, EntryFilter, Input, Journal, Jump,
+        Jumplist, Marks, PathSelection, Selection,
+    },
+    config::MenuElement,
+    highlight::Line,
+    job::{Job, Jobs},
+    tui,
+    watcher::DirWatcher,
 };
 use std::{
     collections::HashMap,
     path::{Path, PathBuf},
     sync::{
-        RwLock,
+        Arc, RwLock,
         atomic::{AtomicBool, AtomicU8, AtomicU16, AtomicUsize},
     },
 };
@@ -14,32 +22,75 @@ use viks::Key;
 
 pub struct State {
     pub work_dir: WorkingDir,
+    pub tabs: Tabs,
+    pub pane: Pane,
     pub mode: CurrentMode,
     pub key_buffer: KeyBuffer,
     pub term_size: TerminalRect,
     pub canvas_hashes: CanvasHashes,
     pub flag: FlagState,
     pub file_view: FileView,
-    pub proc_counter: ProcessCounter,
+    pub jobs: Jobs,
     pub input: InputController,
     pub grep: Grep,
+    pub search: Search,
+    pub filter: Grep,
     pub sidemenu: Menu,
+    pub marks: Marks,
+    pub journal: Journal,
+    pub jumplist: Jumplist,
+    pub conflicts: ConflictQueue,
+    pub list: ListView,
+    pub finder: Finder,
+    pub peek: Peek,
+    pub watcher: DirWatcher,
+    pub snapshot: DirSnapshot,
 }
 
 impl State {
     pub fn new(work_dir: PathBuf) -> State {
-        Self {
-            work_dir: WorkingDir::new(work_dir.clone()),
+        let state = Self {
+            tabs: Tabs::new(work_dir.clone()),
+            pane: Pane::new(work_dir.clone()),
+            work_dir: WorkingDir::new(work_dir),
             mode: CurrentMode::new(),
             key_buffer: KeyBuffer::new(),
             term_size: TerminalRect::new(),
             canvas_hashes: CanvasHashes::new(),
             flag: FlagState::new(),
-            file_view: FileView::new(work_dir.clone()),
-            proc_counter: ProcessCounter::new(),
+            file_view: FileView::new(),
+            jobs: Jobs::new(),
             input: InputController::new(),
             grep: Grep::new(),
+            search: Search::new(),
+            filter: Grep::new(),
             sidemenu: Menu::new(),
+            marks: Marks::load(tui::local_path().join("marks")),
+            journal: Journal::new(),
+            jumplist: Jumplist::new(),
+            conflicts: ConflictQueue::new(),
+            list: ListView::new(),
+            finder: Finder::new(),
+            peek: Peek::new(),
+            watcher: DirWatcher::new(),
+            snapshot: DirSnapshot::new(),
+        };
+
+        state.file_view.cursor.resize(state.entries().len());
+        state.sidemenu.resize(state.marks.len());
+
+        state
+    }
+
+    pub fn entries(&self) -> Arc<Vec<Entry>> {
+        self.snapshot
+            .load(&self.work_dir.get(), &self.entry_filter())
+    }
+
+    fn entry_filter(&self) -> EntryFilter {
+        EntryFilter {
+            show_hidden: self.flag.is_hidden_shown.get(),
+            pattern: self.filter.load(),
         }
     }
 }
@@ -66,6 +117,181 @@ impl WorkingDir {
     }
 }
 
+pub struct Tab {
+    pub dir: PathBuf,
+    pub focus: Option<PathBuf>,
+    pub cursor: usize,
+    pub selection: Option<(Option<PathBuf>, Vec<PathBuf>)>,
+    pub cursor_cache: CursorCache,
+    pub grep: String,
+    pub filter: String,
+}
+
+impl Tab {
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir,
+            focus: None,
+            cursor: 0,
+            selection: None,
+            cursor_cache: CursorCache::new(),
+            grep: String::new(),
+            filter: String::new(),
+        }
+    }
+}
+
+pub struct Tabs {
+    tabs: RwLock<Vec<Tab>>,
+    current: AtomicUsize,
+}
+
+impl Tabs {
+    fn new(dir: PathBuf) -> Self {
+        Self {
+            tabs: RwLock::new(vec![Tab::new(dir)]),
+            current: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn current(&self) -> usize {
+        use std::sync::atomic::Ordering;
+
+        self.current.load(Ordering::Relaxed)
+    }
+
+    pub fn len(&self) -> usize {
+        self.tabs.read().unwrap().len()
+    }
+
+    pub fn dirs(&self) -> Vec<PathBuf> {
+        self.tabs
+            .read()
+            .unwrap()
+            .iter()
+            .map(|tab| tab.dir.clone())
+            .collect()
+    }
+
+    pub fn open(&self, live: Tab) {
+        use std::sync::atomic::Ordering;
+
+        let mut tabs = self.tabs.write().unwrap();
+        let current = self.current();
+        let dir = live.dir.clone();
+
+        tabs[current] = live;
+        tabs.insert(current + 1, Tab::new(dir));
+        self.current.store(current + 1, Ordering::Relaxed);
+    }
+
+    pub fn switch(&self, index: usize, live: Tab) -> Tab {
+        use std::{mem, sync::atomic::Ordering};
+
+        let mut tabs = self.tabs.write().unwrap();
+        let dir = tabs[index].dir.clone();
+
+        tabs[self.current()] = live;
+        self.current.store(index, Ordering::Relaxed);
+
+        mem::replace(&mut tabs[index], Tab::new(dir))
+    }
+
+    pub fn close(&self) -> Option<Tab> {
+        use std::{mem, sync::atomic::Ordering};
+
+        let mut tabs = self.tabs.write().unwrap();
+
+        if tabs.len() <= 1 {
+            return None;
+        }
+
+        tabs.remove(self.current());
+
+        let index = self.current().min(tabs.len() - 1);
+        let dir = tabs[index].dir.clone();
+
+        self.current.store(index, Ordering::Relaxed);
+
+        Some(mem::replace(&mut tabs[index], Tab::new(dir)))
+    }
+}
+
+pub struct Pane {
+    tab: RwLock<Tab>,
+    snapshot: DirSnapshot,
+    watcher: DirWatcher,
+}
+
+impl Pane {
+    fn new(dir: PathBuf) -> Self {
+        Self {
+            tab: RwLock::new(Tab::new(dir)),
+            snapshot: DirSnapshot::new(),
+            watcher: DirWatcher::new(),
+        }
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        self.tab.read().unwrap().dir.clone()
+    }
+
+    pub fn swap(&self, live: Tab) -> Tab {
+        use std::mem;
+
+        mem::replace(&mut *self.tab.write().unwrap(), live)
+    }
+
+    pub fn entries(&self, show_hidden: bool) -> Arc<Vec<Entry>> {
+        let tab = self.tab.read().unwrap();
+
+        self.snapshot.load(
+            &tab.dir,
+            &EntryFilter {
+                show_hidden,
+                pattern: tab.filter.clone(),
+            },
+        )
+    }
+
+    pub fn cursor(&self, entries: &[Entry]) -> usize {
+        let tab = self.tab.read().unwrap();
+
+        tab.focus
+            .as_ref()
+            .and_then(|focus| entries.iter().position(|entry| &entry.path == focus))
+            .unwrap_or(tab.cursor.min(entries.len().saturating_sub(1)))
+    }
+
+    pub fn filter(&self) -> String {
+        self.tab.read().unwrap().filter.clone()
+    }
+
+    pub fn grep(&self) -> String {
+        self.tab.read().unwrap().grep.clone()
+    }
+
+    pub fn hidden_len(&self) -> usize {
+        self.snapshot.hidden_len()
+    }
+
+    pub fn version(&self) -> usize {
+        self.snapshot.version()
+    }
+
+    pub fn invalidate(&self) {
+        self.snapshot.invalidate();
+    }
+
+    pub fn sync(&self) {
+        self.watcher.watch(&self.dir());
+
+        if self.watcher.take_changed() {
+            self.invalidate();
+        }
+    }
+}
+
 #[repr(u8)]
 #[derive(PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Mode {
@@ -74,13 +300,16 @@ pub enum Mode {
     Input = 2,
     Search = 3,
     Menu = 4,
+    List = 5,
+    Peek = 6,
+    Filter = 7,
 }
 
 impl Mode {
     pub fn from_u8(i: u8) -> Option<Mode> {
         use std::mem;
 
-        if (0..=4).contains(&i) {
+        if (0..=7).contains(&i) {
             Some(unsafe { mem::transmute::<u8, Mode>(i) })
         } else {
             None
@@ -220,12 +449,24 @@ impl Flag {
 
 pub struct FlagState {
     pub is_sidemenu_opened: Flag,
+    pub is_preview_opened: Flag,
+    pub is_hidden_shown: Flag,
+    pub is_dual_pane: Flag,
+    pub is_right_focused: Flag,
+    pub is_miller: Flag,
 }
 
 impl FlagState {
     fn new() -> Self {
+        use crate::config;
+
         Self {
             is_sidemenu_opened: Flag::new(false),
+            is_preview_opened: Flag::new(false),
+            is_hidden_shown: Flag::new(!config::get().hide_dotfiles),
+            is_dual_pane: Flag::new(false),
+            is_right_focused: Flag::new(false),
+            is_miller: Flag::new(false),
         }
     }
 }
@@ -233,53 +474,17 @@ impl FlagState {
 pub struct FileView {
     pub cursor: Cursor,
     pub cursor_cache: CursorCache,
-    pub selection: Selection,
+    pub selection: PathSelection,
 }
 
 impl FileView {
-    fn new(wd: PathBuf) -> Self {
-        use crate::misc;
-
-        let s = Self {
-            cursor: Cursor::default(),
-            cursor_cache: CursorCache::new(),
-            selection: Selection::new(),
-        };
-
-        s.cursor.resize(misc::child_files_len(&wd));
-
-        s
-    }
-}
-
-pub struct ProcessCounter {
-    count: AtomicUsize,
-}
-
-impl ProcessCounter {
     fn new() -> Self {
         Self {
-            count: AtomicUsize::new(0),
+            cursor: Cursor::default(),
+            cursor_cache: CursorCache::new(),
+            selection: PathSelection::new(),
         }
     }
-
-    pub fn now(&self) -> usize {
-        use std::sync::atomic::Ordering;
-
-        self.count.load(Ordering::Relaxed)
-    }
-
-    pub fn increment(&self) {
-        use std::sync::atomic::Ordering;
-
-        self.count.fetch_add(1, Ordering::Relaxed);
-    }
-
-    pub fn decrement(&self) {
-        use std::sync::atomic::Ordering;
-
-        self.count.fetch_sub(1, Ordering::Relaxed);
-    }
 }
 
 pub struct InputController {
@@ -336,20 +541,361 @@ impl Grep {
     }
 }
 
+pub struct Search {
+    origin: AtomicUsize,
+    history: RwLock<Vec<String>>,
+    history_pos: RwLock<Option<usize>>,
+    draft: RwLock<String>,
+    matches: RwLock<(String, usize, Arc<Vec<usize>>)>,
+}
+
+impl Search {
+    const HISTORY_LIMIT: usize = 100;
+
+    fn new() -> Self {
+        Self {
+            origin: AtomicUsize::new(0),
+            history: RwLock::new(vec![]),
+            history_pos: RwLock::new(None),
+            draft: RwLock::new(String::new()),
+            matches: RwLock::new((String::new(), 0, Arc::new(vec![]))),
+        }
+    }
+
+    pub fn begin(&self, origin: usize) {
+        use std::sync::atomic::Ordering;
+
+        self.origin.store(origin, Ordering::Relaxed);
+        *self.history_pos.write().unwrap() = None;
+    }
+
+    pub fn origin(&self) -> usize {
+        use std::sync::atomic::Ordering;
+
+        self.origin.load(Ordering::Relaxed)
+    }
+
+    pub fn push_history(&self, pattern: &str) {
+        if pattern.is_empty() {
+            return;
+        }
+
+        let mut history = self.history.write().unwrap();
+
+        history.retain(|item| item != pattern);
+        history.push(pattern.to_string());
+
+        if history.len() > Self::HISTORY_LIMIT {
+            history.remove(0);
+        }
+    }
+
+    pub fn history_prev(&self, current: &str) -> Option<String> {
+        let history = self.history.read().unwrap();
+        let mut pos = self.history_pos.write().unwrap();
+
+        let next = match *pos {
+            Some(0) => return None,
+            Some(pos) => pos - 1,
+            None => {
+                *self.draft.write().unwrap() = current.to_string();
+                history.len().checked_sub(1)?
+            }
+        };
+
+        *pos = Some(next);
+
+        history.get(next).cloned()
+    }
+
+    pub fn history_next(&self) -> Option<String> {
+        let history = self.history.read().unwrap();
+        let mut pos = self.history_pos.write().unwrap();
+
+        let next = (*pos)? + 1;
+
+        if next < history.len() {
+            *pos = Some(next);
+
+            history.get(next).cloned()
+        } else {
+            *pos = None;
+
+            Some(self.draft.read().unwrap().clone())
+        }
+    }
+
+    pub fn matches(&self, pattern: &str, entries: &[Entry], version: usize) -> Arc<Vec<usize>> {
+        use crate::misc;
+
+        {
+            let matches = self.matches.read().unwrap();
+
+            if matches.0 == pattern && matches.1 == version {
+                return matches.2.clone();
+            }
+        }
+
+        let found = match misc::smart_regex(pattern) {
+            Some(regex) if !pattern.is_empty() => entries
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| regex.is_match(&entry.name))
+                .map(|(i, _)| i)
+                .collect(),
+            _ => vec![],
+        };
+        let found = Arc::new(found);
+
+        *self.matches.write().unwrap() = (pattern.to_string(), version, found.clone());
+
+        found
+    }
+}
+
+pub struct Finder {
+    generation: AtomicUsize,
+    results: RwLock<Vec<ListItem>>,
+    job: RwLock<Option<Arc<Job>>>,
+    version: AtomicUsize,
+    synced: AtomicUsize,
+}
+
+impl Finder {
+    fn new() -> Self {
+        Self {
+            generation: AtomicUsize::new(0),
+            results: RwLock::new(vec![]),
+            job: RwLock::new(None),
+            version: AtomicUsize::new(0),
+            synced: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn reset(&self) -> usize {
+        use std::sync::atomic::Ordering;
+
+        self.cancel();
+
+        let mut results = self.results.write().unwrap();
+
+        results.clear();
+        self.version.fetch_add(1, Ordering::Relaxed);
+
+        self.generation.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    pub fn attach(&self, job: Arc<Job>) {
+        *self.job.write().unwrap() = Some(job);
+    }
+
+    pub fn cancel(&self) {
+        if let Some(job) = self.job.write().unwrap().take() {
+            job.cancel();
+        }
+    }
+
+    pub fn push(&self, generation: usize, item: ListItem) {
+        use std::sync::atomic::Ordering;
+
+        let mut results = self.results.write().unwrap();
+
+        if self.generation.load(Ordering::Relaxed) == generation {
+            results.push(item);
+            self.version.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.results.read().unwrap().len()
+    }
+
+    pub fn results(&self) -> Vec<ListItem> {
+        use std::sync::atomic::Ordering;
+
+        let results = self.results.read().unwrap();
+
+        self.synced
+            .store(self.version.load(Ordering::Relaxed), Ordering::Relaxed);
+
+        results.clone()
+    }
+
+    pub fn is_changed(&self) -> bool {
+        use std::sync::atomic::Ordering;
+
+        self.version.load(Ordering::Relaxed) != self.synced.load(Ordering::Relaxed)
+    }
+}
+
 pub struct Menu {
     pub cursor: Cursor,
+    elements: RwLock<Vec<MenuElement>>,
 }
 
 impl Menu {
     fn new() -> Self {
         use crate::config;
 
-        let menu = Self {
+        let elements = config::get().menu_elements.clone();
+        let cursor = Cursor::default();
+
+        cursor.resize(elements.len());
+
+        Self {
+            cursor,
+            elements: RwLock::new(elements),
+        }
+    }
+
+    pub fn elements(&self) -> Vec<MenuElement> {
+        self.elements.read().unwrap().clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.elements.read().unwrap().len()
+    }
+
+    pub fn update<F: FnOnce(&mut Vec<MenuElement>)>(&self, f: F) -> Vec<MenuElement> {
+        let mut elements = self.elements.write().unwrap();
+
+        f(&mut elements);
+
+        elements.clone()
+    }
+
+    pub fn resize(&self, marks_len: usize) {
+        self.cursor.resize(self.len() + marks_len);
+    }
+}
+
+#[derive(PartialEq, Eq, Clone, Copy, Hash)]
+pub enum ListKind {
+    Trash,
+    Jobs,
+    JobErrors(usize),
+    Find,
+    Grep,
+    Marks,
+    History,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub enum ListPayload {
+    #[default]
+    None,
+    Line(usize),
+    Job(usize),
+    Mark(char),
+    Jump(Jump),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Default)]
+pub struct ListItem {
+    pub payload: ListPayload,
+    pub path: PathBuf,
+    pub label: String,
+    pub detail: String,
+}
+
+pub struct ListView {
+    kind: RwLock<Option<ListKind>>,
+    items: RwLock<Arc<Vec<ListItem>>>,
+    version: AtomicUsize,
+    pub cursor: Cursor,
+    pub selection: Selection,
+}
+
+impl ListView {
+    fn new() -> Self {
+        Self {
+            kind: RwLock::new(None),
+            items: RwLock::new(Arc::new(vec![])),
+            version: AtomicUsize::new(0),
             cursor: Cursor::default(),
-        };
+            selection: Selection::new(),
+        }
+    }
 
-        menu.cursor.resize(config::get().menu_elements.len());
+    pub fn kind(&self) -> Option<ListKind> {
+        *self.kind.read().unwrap()
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.kind.read().unwrap().is_some()
+    }
+
+    pub fn open(&self, kind: ListKind, items: Vec<ListItem>) {
+        use std::sync::atomic::Ordering;
+
+        self.cursor.resize(items.len());
+        *self.items.write().unwrap() = Arc::new(items);
+        *self.kind.write().unwrap() = Some(kind);
+        self.version.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn close(&self) {
+        use std::sync::atomic::Ordering;
+
+        *self.kind.write().unwrap() = None;
+        *self.items.write().unwrap() = Arc::new(vec![]);
+        self.selection.disable();
+        self.cursor.reset();
+        self.version.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn items(&self) -> Arc<Vec<ListItem>> {
+        self.items.read().unwrap().clone()
+    }
+
+    pub fn version(&self) -> usize {
+        use std::sync::atomic::Ordering;
+
+        self.version.load(Ordering::Relaxed)
+    }
+}
+
+pub struct Peek {
+    target: RwLock<Option<PathBuf>>,
+    info: RwLock<String>,
+    lines: RwLock<Arc<Vec<Line>>>,
+    pub scroll: Cursor,
+}
+
+impl Peek {
+    fn new() -> Self {
+        Self {
+            target: RwLock::new(None),
+            info: RwLock::new(String::new()),
+            lines: RwLock::new(Arc::new(vec![])),
+            scroll: Cursor::default(),
+        }
+    }
+
+    pub fn target(&self) -> Option<PathBuf> {
+        self.target.read().unwrap().clone()
+    }
+
+    pub fn open(&self, target: PathBuf, info: String, lines: Vec<Line>) {
+        self.scroll.reset();
+        self.scroll.resize(lines.len());
+        *self.lines.write().unwrap() = Arc::new(lines);
+        *self.info.write().unwrap() = info;
+        *self.target.write().unwrap() = Some(target);
+    }
+
+    pub fn close(&self) {
+        *self.target.write().unwrap() = None;
+        *self.lines.write().unwrap() = Arc::new(vec![]);
+        self.info.write().unwrap().clear();
+        self.scroll.reset();
+    }
+
+    pub fn info(&self) -> String {
+        self.info.read().unwrap().clone()
+    }
 
-        menu
+    pub fn lines(&self) -> Arc<Vec<Line>> {
+        self.lines.read().unwrap().clone()
     }
 }
@@ -0,0 +1,376 @@
+use crate::misc;
+use chrono::NaiveDateTime;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+struct TrashDir {
+    root: PathBuf,
+    topdir: Option<PathBuf>,
+}
+
+impl TrashDir {
+    fn files(&self) -> PathBuf {
+        self.root.join("files")
+    }
+
+    fn info(&self) -> PathBuf {
+        self.root.join("info")
+    }
+
+    fn prepare(&self) -> io::Result<()> {
+        use std::os::unix::fs::DirBuilderExt;
+
+        let mut builder = fs::DirBuilder::new();
+
+        builder.recursive(true).mode(0o700);
+        builder.create(self.files())?;
+        builder.create(self.info())
+    }
+}
+
+fn uid() -> u32 {
+    unsafe { libc::getuid() }
+}
+
+fn home_trash() -> Option<TrashDir> {
+    Some(TrashDir {
+        root: misc::data_home()?.join("Trash"),
+        topdir: None,
+    })
+}
+
+fn device_of(path: &Path) -> Option<u64> {
+    use std::os::unix::fs::MetadataExt;
+
+    path.symlink_metadata().ok().map(|meta| meta.dev())
+}
+
+fn nearest_existing(path: &Path) -> Option<&Path> {
+    path.ancestors().find(|path| path.exists())
+}
+
+fn mount_topdir(path: &Path) -> Option<PathBuf> {
+    let dev = device_of(path)?;
+
+    path.ancestors()
+        .take_while(|ancestor| device_of(ancestor) == Some(dev))
+        .last()
+        .map(Path::to_path_buf)
+}
+
+fn topdir_trashes(topdir: &Path) -> Vec<TrashDir> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let uid = uid();
+    let mut trashes = vec![];
+    let admin_trash = topdir.join(".Trash");
+
+    if let Ok(meta) = admin_trash.symlink_metadata()
+        && meta.is_dir()
+        && meta.permissions().mode() & 0o1000 != 0
+    {
+        trashes.push(TrashDir {
+            root: admin_trash.join(uid.to_string()),
+            topdir: Some(topdir.to_path_buf()),
+        });
+    }
+
+    trashes.push(TrashDir {
+        root: topdir.join(format!(".Trash-{uid}")),
+        topdir: Some(topdir.to_path_buf()),
+    });
+
+    trashes
+}
+
+fn candidates(path: &Path) -> Vec<TrashDir> {
+    let Some(home) = home_trash() else {
+        log::warn!("Couldn't read the $HOME, try the trash of the mount only");
+
+        return mount_topdir(path)
+            .map(|topdir| topdir_trashes(&topdir))
+            .unwrap_or_default();
+    };
+    let home_dev = nearest_existing(&home.root).and_then(device_of);
+    let path_dev = device_of(path);
+
+    if path_dev.is_none() || home_dev == path_dev {
+        return vec![home];
+    }
+
+    let mut trashes = mount_topdir(path)
+        .map(|topdir| topdir_trashes(&topdir))
+        .unwrap_or_default();
+
+    trashes.push(home);
+
+    trashes
+}
+
+fn absolute(path: &Path) -> io::Result<PathBuf> {
+    let Some(name) = path.file_name() else {
+        return Err(io::ErrorKind::InvalidInput.into());
+    };
+
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.canonicalize()?,
+        _ => std::env::current_dir()?,
+    };
+
+    Ok(parent.join(name))
+}
+
+fn reserve_info(trash: &TrashDir, name: &str) -> io::Result<(String, fs::File)> {
+    let path = Path::new(name);
+    let stem = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or(name.to_string());
+    let extension = path
+        .extension()
+        .map(|s| format!(".{}", s.to_string_lossy()))
+        .unwrap_or_default();
+
+    let mut n = 1;
+
+    loop {
+        let candidate = if n == 1 {
+            name.to_string()
+        } else {
+            format!("{stem}.{n}{extension}")
+        };
+
+        n += 1;
+
+        if trash.files().join(&candidate).symlink_metadata().is_ok() {
+            continue;
+        }
+
+        match fs::File::create_new(trash.info().join(format!("{candidate}.trashinfo"))) {
+            Ok(file) => return Ok((candidate, file)),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+fn put_into(trash: &TrashDir, path: &Path) -> io::Result<PathBuf> {
+    use chrono::Local;
+    use std::io::Write;
+
+    trash.prepare()?;
+
+    let (name, mut info) = reserve_info(trash, &misc::entry_name(path))?;
+    let info_path = trash.info().join(format!("{name}.trashinfo"));
+    let original = match trash.topdir {
+        Some(ref topdir) => path.strip_prefix(topdir).unwrap_or(path),
+        None => path,
+    };
+
+    let res = write!(
+        info,
+        "[Trash Info]\nPath={}\nDeletionDate={}\n",
+        misc::encode_path(original),
+        Local::now().format("%Y-%m-%dT%H:%M:%S")
+    )
+    .and_then(|_| {
+        let trashed = trash.files().join(&name);
+
+        if trash.topdir.is_some() {
+            fs::rename(path, &trashed)?;
+        } else {
+            misc::move_item(path, &trashed)?;
+        }
+
+        Ok(trashed)
+    });
+
+    if res.is_err() {
+        fs::remove_file(info_path).ok();
+    }
+
+    res
+}
+
+pub fn put(path: &Path) -> io::Result<PathBuf> {
+    let path = absolute(path)?;
+    let mut last_err = io::Error::from(io::ErrorKind::NotFound);
+
+    for trash in candidates(&path) {
+        log::info!("Try to trash into the {}", trash.root.to_string_lossy());
+
+        match put_into(&trash, &path) {
+            Ok(trashed) => return Ok(trashed),
+            Err(e) => {
+                log::warn!(
+                    "Trash into the {} is failed\n\t{}",
+                    trash.root.to_string_lossy(),
+                    e.kind()
+                );
+
+                last_err = e;
+            }
+        }
+    }
+
+    Err(last_err)
+}
+
+fn info_path_of(trashed: &Path) -> Option<PathBuf> {
+    let name = trashed.file_name()?.to_string_lossy();
+    let root = trashed.parent()?.parent()?;
+
+    Some(root.join("info").join(format!("{name}.trashinfo")))
+}
+
+pub fn original_path(trashed: &Path) -> Option<PathBuf> {
+    let info = fs::read_to_string(info_path_of(trashed)?).ok()?;
+    let path = info
+        .lines()
+        .find_map(|line| line.strip_prefix("Path="))
+        .map(misc::decode_path)?;
+
+    if path.is_absolute() {
+        return Some(path);
+    }
+
+    let root = trashed.parent()?.parent()?;
+    let topdir = if misc::entry_name(root).starts_with(".Trash-") {
+        root.parent()?
+    } else {
+        root.parent()?.parent()?
+    };
+
+    Some(topdir.join(path))
+}
+
+pub fn restore(trashed: &Path, into: &Path) -> io::Result<()> {
+    if into.symlink_metadata().is_ok() {
+        return Err(io::ErrorKind::AlreadyExists.into());
+    }
+
+    misc::move_item(trashed, into)?;
+
+    if let Some(info) = info_path_of(trashed) {
+        fs::remove_file(info).ok();
+    }
+
+    Ok(())
+}
+
+pub struct TrashEntry {
+    pub trashed: PathBuf,
+    pub original: PathBuf,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub size: Option<u64>,
+}
+
+fn mount_points() -> Vec<PathBuf> {
+    let Ok(mounts) = fs::read_to_string("/proc/self/mounts") else {
+        return vec![];
+    };
+
+    mounts
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(1))
+        .map(|point| point.replace("\\040", " ").replace("\\011", "\t"))
+        .map(PathBuf::from)
+        .collect()
+}
+
+fn all_trashes() -> Vec<TrashDir> {
+    let mut trashes = home_trash().into_iter().collect::<Vec<_>>();
+
+    for topdir in mount_points() {
+        trashes.extend(
+            topdir_trashes(&topdir)
+                .into_iter()
+                .filter(|trash| trash.info().is_dir()),
+        );
+    }
+
+    trashes
+}
+
+pub fn entry_size(path: &Path) -> u64 {
+    use walkdir::WalkDir;
+
+    WalkDir::new(path)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.metadata().ok())
+        .filter(|meta| !meta.is_dir())
+        .map(|meta| meta.len())
+        .sum()
+}
+
+fn read_entry(trash: &TrashDir, info_path: &Path) -> Option<TrashEntry> {
+    let name = info_path
+        .file_name()?
+        .to_str()?
+        .strip_suffix(".trashinfo")?;
+    let trashed = trash.files().join(name);
+
+    let metadata = trashed.symlink_metadata().ok()?;
+
+    let info = fs::read_to_string(info_path).ok()?;
+    let path = info
+        .lines()
+        .find_map(|line| line.strip_prefix("Path="))
+        .map(misc::decode_path)?;
+    let original = match trash.topdir {
+        Some(ref topdir) if path.is_relative() => topdir.join(path),
+        _ => path,
+    };
+    let deleted_at = info
+        .lines()
+        .find_map(|line| line.strip_prefix("DeletionDate="))
+        .and_then(|date| NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S").ok());
+
+    Some(TrashEntry {
+        size: (!metadata.is_dir()).then_some(metadata.len()),
+        trashed,
+        original,
+        deleted_at,
+    })
+}
+
+pub fn list() -> Vec<TrashEntry> {
+    use std::cmp::Reverse;
+
+    let mut entries = all_trashes()
+        .into_iter()
+        .flat_map(|trash| {
+            let infos = fs::read_dir(trash.info())
+                .map(|dir| dir.flatten().map(|entry| entry.path()).collect::<Vec<_>>())
+                .unwrap_or_default();
+
+            infos
+                .into_iter()
+                .filter_map(|info| read_entry(&trash, &info))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    entries.sort_by_key(|entry| Reverse(entry.deleted_at));
+
+    entries
+}
+
+pub fn purge(trashed: &Path) -> io::Result<()> {
+    let metadata = trashed.symlink_metadata()?;
+
+    if metadata.is_dir() {
+        fs::remove_dir_all(trashed)?;
+    } else {
+        fs::remove_file(trashed)?;
+    }
+
+    if let Some(info) = info_path_of(trashed) {
+        fs::remove_file(info).ok();
+    }
+
+    Ok(())
+}
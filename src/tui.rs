@@ -1,18 +1,24 @@
 use crate::state::State;
 use flexi_logger::FlexiLoggerError;
-use std::{io, path::PathBuf, sync::Arc};
+use std::{
+    io,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
 
-fn local_path() -> PathBuf {
-    use std::path::Path;
+static IS_KEYBOARD_ENHANCED: AtomicBool = AtomicBool::new(false);
 
-    let Some(home) = option_env!("HOME") else {
+pub fn local_path() -> PathBuf {
+    use crate::misc;
+
+    let Some(data_home) = misc::data_home() else {
         panic!("Couldn't read the $HOME");
     };
 
-    Path::new(home)
-        .join(".local")
-        .join("share")
-        .join("endolphine")
+    data_home.join("endolphine")
 }
 
 pub fn setup_local() -> io::Result<()> {
@@ -38,16 +44,6 @@ pub fn setup_local() -> io::Result<()> {
         log::info!("The local clipboard successfully created");
     }
 
-    let trash = tmp_dir.join("Trash");
-
-    if !trash.exists() {
-        log::info!("App trash is couldn't find");
-
-        log::info!("Create the app trash...");
-        fs::create_dir_all(&trash)?;
-        log::info!("The app trash successfully created");
-    }
-
     Ok(())
 }
 
@@ -178,6 +174,7 @@ pub fn update_title<D: AsRef<str>>(title: D) {
 pub fn enable() {
     use crossterm::{
         cursor::Hide,
+        event::{KeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
         terminal::{self, DisableLineWrap, EnterAlternateScreen},
     };
     use std::io;
@@ -186,15 +183,31 @@ pub fn enable() {
         log::info!("Enter alternate screen");
         crossterm::execute!(io::stdout(), EnterAlternateScreen, DisableLineWrap, Hide)
     });
+
+    if terminal::supports_keyboard_enhancement().is_ok_and(|c| c)
+        && crossterm::execute!(
+            io::stdout(),
+            PushKeyboardEnhancementFlags(KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES)
+        )
+        .is_ok()
+    {
+        log::info!("Keyboard enhancement enabled");
+        IS_KEYBOARD_ENHANCED.store(true, Ordering::Relaxed);
+    }
 }
 
 pub fn disable() {
     use crossterm::{
         cursor::Show,
+        event::PopKeyboardEnhancementFlags,
         terminal::{self, EnableLineWrap, LeaveAlternateScreen, SetTitle},
     };
     use std::io;
 
+    if IS_KEYBOARD_ENHANCED.swap(false, Ordering::Relaxed) {
+        let _ = crossterm::execute!(io::stdout(), PopKeyboardEnhancementFlags);
+    }
+
     let _ = terminal::disable_raw_mode().and_then(|_| {
         log::info!("Leave alternate screen");
         crossterm::execute!(
@@ -0,0 +1,133 @@
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+    time::{Duration, Instant, SystemTime},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+struct Poll {
+    modified: Option<SystemTime>,
+    checked_at: Instant,
+}
+
+impl Poll {
+    fn new(path: &Path) -> Self {
+        Self {
+            modified: modified(path),
+            checked_at: Instant::now(),
+        }
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    path.metadata().and_then(|meta| meta.modified()).ok()
+}
+
+pub struct DirWatcher {
+    watcher: Mutex<Option<RecommendedWatcher>>,
+    target: RwLock<Option<PathBuf>>,
+    is_changed: Arc<AtomicBool>,
+    poll: Mutex<Option<Poll>>,
+}
+
+impl DirWatcher {
+    pub fn new() -> Self {
+        Self {
+            watcher: Mutex::new(None),
+            target: RwLock::new(None),
+            is_changed: Arc::new(AtomicBool::new(false)),
+            poll: Mutex::new(None),
+        }
+    }
+
+    fn spawn(&self) -> notify::Result<RecommendedWatcher> {
+        let is_changed = self.is_changed.clone();
+
+        notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+            if res.is_ok_and(|event| !event.kind.is_access()) {
+                is_changed.store(true, Ordering::Relaxed);
+            }
+        })
+    }
+
+    pub fn watch(&self, path: &Path) {
+        if self.target.read().unwrap().as_deref() == Some(path) {
+            return;
+        }
+
+        let prev = self.target.write().unwrap().replace(path.to_path_buf());
+
+        self.is_changed.store(true, Ordering::Relaxed);
+
+        let mut watcher = self.watcher.lock().unwrap();
+
+        if watcher.is_none() {
+            match self.spawn() {
+                Ok(spawned) => *watcher = Some(spawned),
+                Err(e) => {
+                    log::warn!("The directory watcher couldn't start, poll instead: {e}");
+
+                    *self.poll.lock().unwrap() = Some(Poll::new(path));
+
+                    return;
+                }
+            }
+        }
+
+        let Some(watcher) = watcher.as_mut() else {
+            return;
+        };
+
+        if let Some(prev) = prev {
+            watcher.unwatch(&prev).ok();
+        }
+
+        let poll = match watcher.watch(path, RecursiveMode::NonRecursive) {
+            Ok(_) => None,
+            Err(e) => {
+                log::warn!(
+                    "Couldn't watch the '{}', poll instead: {e}",
+                    path.to_string_lossy()
+                );
+
+                Some(Poll::new(path))
+            }
+        };
+
+        *self.poll.lock().unwrap() = poll;
+    }
+
+    pub fn take_changed(&self) -> bool {
+        if self.is_changed.swap(false, Ordering::Relaxed) {
+            return true;
+        }
+
+        let mut poll = self.poll.lock().unwrap();
+
+        let Some(poll) = poll.as_mut() else {
+            return false;
+        };
+
+        if poll.checked_at.elapsed() < POLL_INTERVAL {
+            return false;
+        }
+
+        poll.checked_at = Instant::now();
+
+        let Some(path) = self.target.read().unwrap().clone() else {
+            return false;
+        };
+
+        let modified = modified(&path);
+        let is_changed = modified != poll.modified;
+
+        poll.modified = modified;
+
+        is_changed
+    }
+}